@@ -1,22 +1,44 @@
+pub mod affine;
+pub mod complex;
+pub mod coords;
+pub mod iterative;
 pub mod matrix;
+pub mod quat;
+pub mod scalar;
+pub mod sparse;
 pub mod vector;
 
 /// The type of coordinate system used
 ///
+/// Vec2 only has one non-cartesian form, so it treats POLAR, CYLINDRICAL and SPHERICAL alike as polar.
+/// For Vec3, POLAR is kept as another name for SPHERICAL. To read the components of a vector in another
+/// system, convert it into one of the Polar, Cylindrical or Spherical structs in coords
+///
 /// # Examples
 ///
 /// ```
+/// use std::f32::consts::PI;
+/// use miscmath::prelude::*;
 ///
+/// let mut a = Vec3::default();
+/// let mut b = Vec3::default();
+/// a.set( &5.0, &0.6435011, &( PI / 2.0 ), &CoordSystem::POLAR );
+/// b.set( &5.0, &0.6435011, &( PI / 2.0 ), &CoordSystem::SPHERICAL );
 ///
-///
+/// assert_eq!( a, b );
+/// assert!( ( Spherical::from( a ).r - 5.0 ).abs() < 0.00001 );
 /// ```
 ///
 #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
 pub enum CoordSystem {
-    ///
+    /// Components are x, y (and z)
     CARTESIAN,
-    ///
+    /// Components are a magnitude followed by angles
     POLAR,
+    /// Components are the distance from the z axis, the azimuth phi and z
+    CYLINDRICAL,
+    /// Components are the radius, the polar angle theta and the azimuth phi
+    SPHERICAL,
 }
 
 /// Unitary float, a f32 which must be in the closed interval [0.0,1.0]
@@ -31,7 +53,7 @@ pub enum CoordSystem {
 ///
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct UnitF {
-    ///
+    /// The wrapped value, always within [0.0,1.0]
     value: f32,
 }
 
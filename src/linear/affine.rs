@@ -0,0 +1,337 @@
+use super::matrix::Mat3;
+use super::vector::Vec2;
+
+use std::ops::{Mul, MulAssign};
+
+/// A 2D affine transform of f32, stored as the top two rows of a 3x3 homogeneous matrix
+///
+/// Points are transformed as column vectors, so in a * b the transform b is applied first.
+/// The builder style methods translate, rotate and scale apply in the local space of self,
+/// matching the push/pop style of p5.js
+///
+/// # Examples
+///
+/// ```
+/// use std::f32::consts::FRAC_PI_2;
+/// use miscmath::prelude::*;
+///
+/// let a = Affine2::from_translation( &Vec2::new( &10.0, &0.0 ) ) * Affine2::from_rotation( &FRAC_PI_2 );
+///
+/// assert_eq!( a.transform_point( &Vec2::new( &1.0, &0.0 ) ), Vec2::new( &10.0, &1.0 ) );
+/// assert_eq!( a.transform_vector( &Vec2::new( &1.0, &0.0 ) ), Vec2::new( &0.0, &1.0 ) );
+/// assert_eq!( a * a.inverse().unwrap(), Affine2::identity() );
+/// ```
+///
+#[derive(Copy, Clone, Debug)]
+pub struct Affine2 {
+    /// The elements of the transform, indexed as m[ row ][ column ], the last column is the translation
+    pub m: [[f32; 3]; 2],
+}
+
+/// Implements Default for Affine2, the identity transform
+impl Default for Affine2 {
+    fn default() -> Self {
+        Affine2::identity()
+    }
+}
+
+/// Implements Mul for Affine2, composing the transforms so rhs is applied first
+impl Mul for Affine2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (&self.m, &rhs.m);
+        let mut m = [[0.0; 3]; 2];
+        for (i, row) in m.iter_mut().enumerate() {
+            row[0] = a[i][0] * b[0][0] + a[i][1] * b[1][0];
+            row[1] = a[i][0] * b[0][1] + a[i][1] * b[1][1];
+            row[2] = a[i][0] * b[0][2] + a[i][1] * b[1][2] + a[i][2];
+        }
+        Affine2 { m }
+    }
+}
+
+/// Implements MulAssign for Affine2
+impl MulAssign for Affine2 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Implements Mul of a Vec2 by Affine2, treating the Vec2 as a point
+impl Mul<Vec2> for Affine2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        self.transform_point(&rhs)
+    }
+}
+
+/// Implements PartialEq for Affine2, elements are compared within a tolerance
+impl PartialEq for Affine2 {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.m
+            .iter()
+            .flatten()
+            .zip(rhs.m.iter().flatten())
+            .all(|(a, b)| (a - b).abs() < 0.00001)
+    }
+}
+
+impl Affine2 {
+    /// Generates a new instance from the two rows of the transform and returns it
+    pub fn from_rows(rows: &[[f32; 3]; 2]) -> Affine2 {
+        Affine2 { m: *rows }
+    }
+
+    /// Generates a new identity transform and returns it
+    pub fn identity() -> Affine2 {
+        Affine2 {
+            m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+        }
+    }
+
+    /// Generates a new transform which translates by the entered offset and returns it
+    pub fn from_translation(offset: &Vec2) -> Affine2 {
+        Affine2 {
+            m: [[1.0, 0.0, offset.x], [0.0, 1.0, offset.y]],
+        }
+    }
+
+    /// Generates a new transform which rotates counterclockwise by the entered angle and returns it
+    pub fn from_rotation(angle: &f32) -> Affine2 {
+        let (sin, cos) = angle.sin_cos();
+        Affine2 {
+            m: [[cos, -sin, 0.0], [sin, cos, 0.0]],
+        }
+    }
+
+    /// Generates a new transform which scales each axis by the entered factors and returns it
+    pub fn from_scale(factors: &Vec2) -> Affine2 {
+        Affine2 {
+            m: [[factors.x, 0.0, 0.0], [0.0, factors.y, 0.0]],
+        }
+    }
+
+    /// Generates a new transform which scales, then rotates, then translates and returns it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Affine2::from_trs( &Vec2::new( &3.0, &-1.0 ), &0.5, &Vec2::new( &2.0, &0.5 ) );
+    /// let ( translation, rotation, scale ) = a.decompose();
+    ///
+    /// assert_eq!( translation, Vec2::new( &3.0, &-1.0 ) );
+    /// assert!( ( rotation - 0.5 ).abs() < 0.00001 );
+    /// assert_eq!( scale, Vec2::new( &2.0, &0.5 ) );
+    /// ```
+    ///
+    pub fn from_trs(translation: &Vec2, rotation: &f32, scale: &Vec2) -> Affine2 {
+        Affine2::from_translation(translation)
+            * Affine2::from_rotation(rotation)
+            * Affine2::from_scale(scale)
+    }
+
+    /// Translates self by the entered offset, applied in the local space of self
+    pub fn translate(&mut self, offset: &Vec2) {
+        *self *= Affine2::from_translation(offset);
+    }
+
+    /// Rotates self counterclockwise by the entered angle, applied in the local space of self
+    pub fn rotate(&mut self, angle: &f32) {
+        *self *= Affine2::from_rotation(angle);
+    }
+
+    /// Scales self by the entered factors, applied in the local space of self
+    pub fn scale(&mut self, factors: &Vec2) {
+        *self *= Affine2::from_scale(factors);
+    }
+
+    /// Returns the translation column of self
+    pub fn translation(&self) -> Vec2 {
+        Vec2::new(&self.m[0][2], &self.m[1][2])
+    }
+
+    /// Calculates the determinant of the linear part of self
+    pub fn determinant(&self) -> f32 {
+        self.m[0][0] * self.m[1][1] - self.m[0][1] * self.m[1][0]
+    }
+
+    /// Calculates the inverse of self, returns None if self is singular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// // Small scales are not mistaken for singular transforms
+    /// let small = Affine2::from_scale( &Vec2::new( &1e-4, &1e-4 ) );
+    /// let p = small.inverse().unwrap().transform_point( &Vec2::new( &1e-4, &2e-4 ) );
+    ///
+    /// assert!( ( p - Vec2::new( &1.0, &2.0 ) ).mag() < 0.0001 );
+    /// assert_eq!( Affine2::from_scale( &Vec2::new( &0.0, &1.0 ) ).inverse(), None );
+    /// ```
+    ///
+    pub fn inverse(&self) -> Option<Affine2> {
+        /* Only an exact zero is singular, a tolerance would reject uniformly scaled down transforms */
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+
+        let m = &self.m;
+        let (a, b, c, d) = (m[1][1] / det, -m[0][1] / det, -m[1][0] / det, m[0][0] / det);
+        Some(Affine2 {
+            m: [
+                [a, b, -(a * m[0][2] + b * m[1][2])],
+                [c, d, -(c * m[0][2] + d * m[1][2])],
+            ],
+        })
+    }
+
+    /// Applies self to a point, including the translation
+    pub fn transform_point(&self, point: &Vec2) -> Vec2 {
+        let v = self.transform_vector(point);
+        Vec2::new(&(v.x + self.m[0][2]), &(v.y + self.m[1][2]))
+    }
+
+    /// Applies self to a direction, ignoring the translation
+    pub fn transform_vector(&self, vector: &Vec2) -> Vec2 {
+        let m = &self.m;
+        Vec2::new(
+            &(m[0][0] * vector.x + m[0][1] * vector.y),
+            &(m[1][0] * vector.x + m[1][1] * vector.y),
+        )
+    }
+
+    /// Splits self into a translation, a counterclockwise rotation and a scale, such that
+    /// Affine2::from_trs rebuilds self
+    ///
+    /// Any shear in self is lost. A reflection is reported as a negative y scale
+    pub fn decompose(&self) -> (Vec2, f32, Vec2) {
+        let m = &self.m;
+        let scale_x = m[0][0].hypot(m[1][0]);
+        let rotation = m[1][0].atan2(m[0][0]);
+        let scale_y = if scale_x == 0.0 {
+            m[0][1].hypot(m[1][1])
+        } else {
+            self.determinant() / scale_x
+        };
+
+        (self.translation(), rotation, Vec2::new(&scale_x, &scale_y))
+    }
+
+    /// Generates the equivalent 3x3 homogeneous matrix
+    pub fn to_mat3(&self) -> Mat3 {
+        Mat3::from_rows(&[self.m[0], self.m[1], [0.0, 0.0, 1.0]])
+    }
+}
+
+/// Implements From<Affine2> for Mat3
+impl From<Affine2> for Mat3 {
+    fn from(affine: Affine2) -> Self {
+        affine.to_mat3()
+    }
+}
+
+/// A stack of 2D transforms with push/pop semantics, like the drawing state in p5.js
+///
+/// # Examples
+///
+/// ```
+/// use std::f32::consts::PI;
+/// use miscmath::prelude::*;
+///
+/// let mut stack = TransformStack::new();
+/// stack.translate( &Vec2::new( &100.0, &50.0 ) );
+///
+/// stack.push();
+/// stack.rotate( &PI );
+/// assert_eq!( stack.apply_point( &Vec2::new( &10.0, &0.0 ) ), Vec2::new( &90.0, &50.0 ) );
+/// assert_eq!( stack.depth(), 1 );
+///
+/// assert!( stack.pop() );
+/// assert_eq!( stack.apply_point( &Vec2::new( &10.0, &0.0 ) ), Vec2::new( &110.0, &50.0 ) );
+///
+/// // Popping more than was pushed leaves the current transform alone
+/// assert!( !stack.pop() );
+/// assert_eq!( stack.current().translation(), Vec2::new( &100.0, &50.0 ) );
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransformStack {
+    /// The transform in effect
+    current: Affine2,
+    /// The transforms saved by push, most recent last
+    saved: Vec<Affine2>,
+}
+
+impl TransformStack {
+    /// Generates a new stack holding only the identity transform and returns it
+    pub fn new() -> TransformStack {
+        TransformStack::default()
+    }
+
+    /// Returns the transform in effect
+    pub fn current(&self) -> &Affine2 {
+        &self.current
+    }
+
+    /// Returns how many transforms have been pushed and not yet popped
+    pub fn depth(&self) -> usize {
+        self.saved.len()
+    }
+
+    /// Saves the current transform so a later pop can restore it
+    pub fn push(&mut self) {
+        self.saved.push(self.current);
+    }
+
+    /// Restores the transform saved by the most recent push, returns false if there was none
+    pub fn pop(&mut self) -> bool {
+        match self.saved.pop() {
+            Some(saved) => {
+                self.current = saved;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Resets the current transform to the identity, leaving saved transforms in place
+    pub fn reset(&mut self) {
+        self.current = Affine2::identity();
+    }
+
+    /// Applies a transform to the current one, in its local space
+    pub fn apply(&mut self, transform: &Affine2) {
+        self.current *= *transform;
+    }
+
+    /// Translates the current transform by the entered offset
+    pub fn translate(&mut self, offset: &Vec2) {
+        self.current.translate(offset);
+    }
+
+    /// Rotates the current transform counterclockwise by the entered angle
+    pub fn rotate(&mut self, angle: &f32) {
+        self.current.rotate(angle);
+    }
+
+    /// Scales the current transform by the entered factors
+    pub fn scale(&mut self, factors: &Vec2) {
+        self.current.scale(factors);
+    }
+
+    /// Applies the current transform to a point
+    pub fn apply_point(&self, point: &Vec2) -> Vec2 {
+        self.current.transform_point(point)
+    }
+
+    /// Applies the current transform to a direction, ignoring the translation
+    pub fn apply_vector(&self, vector: &Vec2) -> Vec2 {
+        self.current.transform_vector(vector)
+    }
+}
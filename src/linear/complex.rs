@@ -0,0 +1,71 @@
+use super::scalar::Float;
+
+use std::fmt;
+
+/// A complex number with a real and an imaginary part
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = Complex::new( 3.0, -4.0 );
+///
+/// assert_eq!( a.norm(), 5.0 );
+/// assert_eq!( a.conj(), Complex::new( 3.0, 4.0 ) );
+/// assert_eq!( a.to_string(), "3-4i" );
+/// ```
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Complex<T> {
+    /// The real part
+    pub re: T,
+    /// The imaginary part
+    pub im: T,
+}
+
+/// Implements Display for Complex, formatted as a+bi
+impl<T: Float + fmt::Display> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < T::ZERO {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+impl<T: Float> Complex<T> {
+    /// Generates a new instance of Complex from its real and imaginary parts and returns it
+    pub fn new(re: T, im: T) -> Complex<T> {
+        Complex { re, im }
+    }
+
+    /// Generates a new instance of Complex with no imaginary part and returns it
+    pub fn from_real(re: T) -> Complex<T> {
+        Complex { re, im: T::ZERO }
+    }
+
+    /// Returns the complex conjugate of self
+    pub fn conj(&self) -> Complex<T> {
+        Complex {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// Calculates the magnitude of self
+    pub fn norm(&self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    /// Calculates the angle of self from the positive real axis
+    pub fn arg(&self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    /// Returns true if the imaginary part of self is within tolerance of zero
+    pub fn is_real(&self, tolerance: T) -> bool {
+        self.im.abs() <= tolerance
+    }
+}
@@ -0,0 +1,193 @@
+use super::scalar::Float;
+use super::vector::{Vec2, Vec3};
+use super::CoordSystem::*;
+
+/// A point in two dimensional polar coordinates
+///
+/// # Examples
+///
+/// ```
+/// use std::f32::consts::PI;
+/// use miscmath::prelude::*;
+///
+/// let a = Polar::new( &2.0, &( PI / 2.0 ) );
+/// let b: Vec2 = a.into();
+///
+/// assert_eq!( b, Vec2::new( &0.0, &2.0 ) );
+/// assert!( ( Polar::from( b ).theta - PI / 2.0 ).abs() < 0.00001 );
+/// ```
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Polar<T> {
+    /// The distance from the origin
+    pub r: T,
+    /// The angle from the +x axis towards +y
+    pub theta: T,
+}
+
+/// A point in cylindrical coordinates
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = Cylindrical::from( Vec3::new( &3.0, &3.0, &-1.0 ) );
+///
+/// assert!( ( a.rho - 18.0_f32.sqrt() ).abs() < 0.00001 );
+/// assert!( ( a.phi - std::f32::consts::FRAC_PI_4 ).abs() < 0.00001 );
+/// assert_eq!( a.z, -1.0 );
+/// assert_eq!( Vec3::from( a ), Vec3::new( &3.0, &3.0, &-1.0 ) );
+/// ```
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Cylindrical<T> {
+    /// The distance from the z axis
+    pub rho: T,
+    /// The azimuth, the angle in the xy plane from the +x axis towards +y
+    pub phi: T,
+    /// The height along the z axis
+    pub z: T,
+}
+
+/// A point in spherical coordinates, following the same convention as Vec3
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::PI;
+/// use miscmath::prelude::*;
+///
+/// let a = Spherical::new( &2.0, &( PI / 2.0 ), &PI );
+/// let b: Vec3d = a.into();
+///
+/// assert_eq!( b, Vec3::new( &-2.0, &0.0, &0.0 ) );
+///
+/// // Spherical and cylindrical coordinates agree on the azimuth
+/// let c = Cylindrical::from( a );
+///
+/// assert!( ( c.rho - 2.0 ).abs() < 1e-12 && ( c.phi - PI ).abs() < 1e-12 && c.z.abs() < 1e-12 );
+/// ```
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Spherical<T> {
+    /// The distance from the origin
+    pub r: T,
+    /// The polar angle from the +z axis
+    pub theta: T,
+    /// The azimuth, the angle in the xy plane from the +x axis towards +y
+    pub phi: T,
+}
+
+impl<T: Float> Polar<T> {
+    /// Generates a new instance of Polar from a radius and an angle and returns it
+    pub fn new(r: &T, theta: &T) -> Polar<T> {
+        Polar {
+            r: *r,
+            theta: *theta,
+        }
+    }
+}
+
+impl<T: Float> Cylindrical<T> {
+    /// Generates a new instance of Cylindrical from a distance from the z axis, an azimuth and a height and returns it
+    pub fn new(rho: &T, phi: &T, z: &T) -> Cylindrical<T> {
+        Cylindrical {
+            rho: *rho,
+            phi: *phi,
+            z: *z,
+        }
+    }
+}
+
+impl<T: Float> Spherical<T> {
+    /// Generates a new instance of Spherical from a radius, a polar angle and an azimuth and returns it
+    pub fn new(r: &T, theta: &T, phi: &T) -> Spherical<T> {
+        Spherical {
+            r: *r,
+            theta: *theta,
+            phi: *phi,
+        }
+    }
+}
+
+/// Implements From<Vec2<T>> for Polar<T>
+impl<T: Float> From<Vec2<T>> for Polar<T> {
+    fn from(mut vec: Vec2<T>) -> Self {
+        vec.swap_system(POLAR);
+        Polar {
+            r: vec.x,
+            theta: vec.y,
+        }
+    }
+}
+
+/// Implements From<Polar<T>> for Vec2<T>
+impl<T: Float> From<Polar<T>> for Vec2<T> {
+    fn from(polar: Polar<T>) -> Self {
+        let mut vec = Vec2::default();
+        vec.set(&polar.r, &polar.theta, &POLAR);
+        vec
+    }
+}
+
+/// Implements From<Vec3<T>> for Cylindrical<T>
+impl<T: Float> From<Vec3<T>> for Cylindrical<T> {
+    fn from(mut vec: Vec3<T>) -> Self {
+        vec.swap_system(CYLINDRICAL);
+        Cylindrical {
+            rho: vec.x,
+            phi: vec.y,
+            z: vec.z,
+        }
+    }
+}
+
+/// Implements From<Cylindrical<T>> for Vec3<T>
+impl<T: Float> From<Cylindrical<T>> for Vec3<T> {
+    fn from(cylindrical: Cylindrical<T>) -> Self {
+        let mut vec = Vec3::default();
+        vec.set(
+            &cylindrical.rho,
+            &cylindrical.phi,
+            &cylindrical.z,
+            &CYLINDRICAL,
+        );
+        vec
+    }
+}
+
+/// Implements From<Vec3<T>> for Spherical<T>
+impl<T: Float> From<Vec3<T>> for Spherical<T> {
+    fn from(mut vec: Vec3<T>) -> Self {
+        vec.swap_system(SPHERICAL);
+        Spherical {
+            r: vec.x,
+            theta: vec.y,
+            phi: vec.z,
+        }
+    }
+}
+
+/// Implements From<Spherical<T>> for Vec3<T>
+impl<T: Float> From<Spherical<T>> for Vec3<T> {
+    fn from(spherical: Spherical<T>) -> Self {
+        let mut vec = Vec3::default();
+        vec.set(&spherical.r, &spherical.theta, &spherical.phi, &SPHERICAL);
+        vec
+    }
+}
+
+/// Implements From<Spherical<T>> for Cylindrical<T>
+impl<T: Float> From<Spherical<T>> for Cylindrical<T> {
+    fn from(spherical: Spherical<T>) -> Self {
+        Cylindrical::from(Vec3::from(spherical))
+    }
+}
+
+/// Implements From<Cylindrical<T>> for Spherical<T>
+impl<T: Float> From<Cylindrical<T>> for Spherical<T> {
+    fn from(cylindrical: Cylindrical<T>) -> Self {
+        Spherical::from(Vec3::from(cylindrical))
+    }
+}
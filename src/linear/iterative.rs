@@ -0,0 +1,582 @@
+use super::matrix::{DMatrix, MatrixError};
+use super::scalar::{Float, Scalar};
+use super::sparse::{CscMatrix, CsrMatrix};
+
+/// Anything which can be multiplied by a dense vector, dense or sparse, so the iterative solvers can use it
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = CsrMatrix::from_dense( &DMatrix::new( 2, 2, vec![ 2.0, 1.0, 0.0, 3.0 ] ).unwrap() );
+///
+/// assert_eq!( a.apply( &[ 1.0, 1.0 ] ).unwrap(), vec![ 3.0, 3.0 ] );
+/// assert_eq!( a.diagonal(), vec![ 2.0, 3.0 ] );
+/// ```
+///
+pub trait LinearOperator<T> {
+    /// Returns the number of rows of the operator
+    fn nrows(&self) -> usize;
+
+    /// Returns the number of columns of the operator
+    fn ncols(&self) -> usize;
+
+    /// Multiplies the operator by the dense vector x
+    fn apply(&self, x: &[T]) -> Result<Vec<T>, MatrixError>;
+
+    /// Returns the main diagonal of the operator, used for Jacobi preconditioning
+    fn diagonal(&self) -> Vec<T>;
+}
+
+/// Implements LinearOperator for DMatrix
+impl<T: Scalar> LinearOperator<T> for DMatrix<T> {
+    fn nrows(&self) -> usize {
+        DMatrix::nrows(self)
+    }
+
+    fn ncols(&self) -> usize {
+        DMatrix::ncols(self)
+    }
+
+    fn apply(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+        self.mul_vec(x)
+    }
+
+    fn diagonal(&self) -> Vec<T> {
+        (0..DMatrix::nrows(self).min(DMatrix::ncols(self)))
+            .map(|i| self[(i, i)])
+            .collect()
+    }
+}
+
+/// Implements LinearOperator for CsrMatrix
+impl<T: Scalar> LinearOperator<T> for CsrMatrix<T> {
+    fn nrows(&self) -> usize {
+        self.shape().0
+    }
+
+    fn ncols(&self) -> usize {
+        self.shape().1
+    }
+
+    fn apply(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+        self.mul_vec(x)
+    }
+
+    fn diagonal(&self) -> Vec<T> {
+        let (rows, cols) = self.shape();
+        (0..rows.min(cols))
+            .map(|i| self.get(i, i).unwrap_or(T::ZERO))
+            .collect()
+    }
+}
+
+/// Implements LinearOperator for CscMatrix
+impl<T: Scalar> LinearOperator<T> for CscMatrix<T> {
+    fn nrows(&self) -> usize {
+        self.shape().0
+    }
+
+    fn ncols(&self) -> usize {
+        self.shape().1
+    }
+
+    fn apply(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+        self.mul_vec(x)
+    }
+
+    fn diagonal(&self) -> Vec<T> {
+        let (rows, cols) = self.shape();
+        (0..rows.min(cols))
+            .map(|i| self.get(i, i).unwrap_or(T::ZERO))
+            .collect()
+    }
+}
+
+/// The preconditioner applied by the iterative solvers
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Preconditioner {
+    /// No preconditioning
+    Identity,
+    /// Scales by the inverse of the diagonal of the operator, which must have no zeros on it
+    Jacobi,
+}
+
+/// Settings shared by the iterative solvers
+///
+/// The solvers stop once the residual norm relative to the norm of b is at or below the tolerance,
+/// or once max_iterations have been used. The default tolerance is the square root of epsilon
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let config = SolverConfig {
+///     tolerance: 1e-12,
+///     preconditioner: Preconditioner::Jacobi,
+///     ..SolverConfig::default()
+/// };
+///
+/// assert_eq!( config.max_iterations, 1000 );
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SolverConfig<T> {
+    /// The relative residual at which the solver stops
+    pub tolerance: T,
+    /// The most iterations the solver may use
+    pub max_iterations: usize,
+    /// The preconditioner to apply
+    pub preconditioner: Preconditioner,
+}
+
+/// Implements Default for SolverConfig
+impl<T: Float> Default for SolverConfig<T> {
+    fn default() -> Self {
+        SolverConfig {
+            tolerance: T::EPSILON.sqrt(),
+            max_iterations: 1000,
+            preconditioner: Preconditioner::Identity,
+        }
+    }
+}
+
+/// How an iterative solve went
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ConvergenceReport<T> {
+    /// The number of iterations used
+    pub iterations: usize,
+    /// The final residual norm relative to the norm of b
+    pub residual: T,
+    /// Whether the residual reached the tolerance
+    pub converged: bool,
+}
+
+/* Calculates the dot product of two vectors */
+fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b).fold(T::ZERO, |acc, (x, y)| acc + *x * *y)
+}
+
+/* Calculates the euclidean norm of a vector */
+fn norm<T: Float>(a: &[T]) -> T {
+    dot(a, a).sqrt()
+}
+
+/* Checks the operator is square and matches b, then builds the inverse diagonal for the preconditioner */
+fn prepare<T: Float, A: LinearOperator<T>>(
+    a: &A,
+    b: &[T],
+    config: &SolverConfig<T>,
+) -> Result<Option<Vec<T>>, MatrixError> {
+    let n = a.nrows();
+    if n != a.ncols() {
+        return Err(MatrixError::NotSquare {
+            shape: (n, a.ncols()),
+        });
+    }
+    if b.len() != n {
+        return Err(MatrixError::DimensionMismatch {
+            expected: (n, 1),
+            found: (b.len(), 1),
+        });
+    }
+    match config.preconditioner {
+        Preconditioner::Identity => Ok(None),
+        Preconditioner::Jacobi => {
+            let diagonal = a.diagonal();
+            if diagonal.contains(&T::ZERO) {
+                return Err(MatrixError::Singular);
+            }
+            Ok(Some(diagonal.iter().map(|d| T::ONE / *d).collect()))
+        }
+    }
+}
+
+/* Applies the preconditioner to a vector */
+fn precondition<T: Float>(inverse_diagonal: &Option<Vec<T>>, v: &[T]) -> Vec<T> {
+    match inverse_diagonal {
+        Some(inv) => v.iter().zip(inv).map(|(x, d)| *x * *d).collect(),
+        None => v.to_vec(),
+    }
+}
+
+/// Solves A * x = b for x with the conjugate gradient method, A must be symmetric positive-definite
+///
+/// Fails with NotPositiveDefinite if a search direction with non-positive curvature is met
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// // A 1D Poisson problem, tridiagonal with 2 on the diagonal and -1 beside it
+/// let n = 50;
+/// let mut coo = CooMatrix::new( n, n );
+/// for i in 0..n {
+///     coo.push( i, i, 2.0 ).unwrap();
+///     if i > 0 {
+///         coo.push( i, i - 1, -1.0 ).unwrap();
+///         coo.push( i - 1, i, -1.0 ).unwrap();
+///     }
+/// }
+/// let a = coo.to_csr();
+/// let b = vec![ 1.0; n ];
+///
+/// let ( x, report ) = conjugate_gradient( &a, &b, &SolverConfig::default() ).unwrap();
+/// let ax = a.mul_vec( &x ).unwrap();
+///
+/// assert!( report.converged );
+/// assert!( report.iterations <= n );
+/// for ( found, expected ) in ax.iter().zip( &b ) {
+///     assert!( ( found - expected ).abs() < 1e-6 );
+/// }
+/// ```
+///
+pub fn conjugate_gradient<T: Float, A: LinearOperator<T>>(
+    a: &A,
+    b: &[T],
+    config: &SolverConfig<T>,
+) -> Result<(Vec<T>, ConvergenceReport<T>), MatrixError> {
+    let inverse_diagonal = prepare(a, b, config)?;
+    let n = b.len();
+    let b_norm = norm(b);
+    let mut x = vec![T::ZERO; n];
+    if b_norm == T::ZERO {
+        return Ok((
+            x,
+            ConvergenceReport {
+                iterations: 0,
+                residual: T::ZERO,
+                converged: true,
+            },
+        ));
+    }
+
+    let mut r = b.to_vec();
+    let mut z = precondition(&inverse_diagonal, &r);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+    let mut residual = T::ONE;
+
+    for iteration in 1..=config.max_iterations {
+        let ap = a.apply(&p)?;
+        let curvature = dot(&p, &ap);
+        if curvature <= T::ZERO {
+            return Err(MatrixError::NotPositiveDefinite);
+        }
+        let alpha = rz / curvature;
+        for i in 0..n {
+            x[i] += alpha * p[i];
+            r[i] -= alpha * ap[i];
+        }
+
+        residual = norm(&r) / b_norm;
+        if residual <= config.tolerance {
+            return Ok((
+                x,
+                ConvergenceReport {
+                    iterations: iteration,
+                    residual,
+                    converged: true,
+                },
+            ));
+        }
+
+        z = precondition(&inverse_diagonal, &r);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        for i in 0..n {
+            p[i] = z[i] + beta * p[i];
+        }
+    }
+
+    Ok((
+        x,
+        ConvergenceReport {
+            iterations: config.max_iterations,
+            residual,
+            converged: false,
+        },
+    ))
+}
+
+/// Solves A * x = b for x with the stabilized biconjugate gradient method, for general square A
+///
+/// The preconditioner is applied on the right, so the reported residual is that of the original system.
+/// If the method breaks down the current estimate is returned with converged set to false
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// // A non-symmetric convection-diffusion style matrix
+/// let n = 30;
+/// let mut coo = CooMatrix::new( n, n );
+/// for i in 0..n {
+///     coo.push( i, i, 4.0 ).unwrap();
+///     if i > 0 {
+///         coo.push( i, i - 1, -2.0 ).unwrap();
+///         coo.push( i - 1, i, -0.5 ).unwrap();
+///     }
+/// }
+/// let a = coo.to_csc();
+/// let b = vec![ 1.0; n ];
+///
+/// let config = SolverConfig { preconditioner: Preconditioner::Jacobi, ..SolverConfig::default() };
+/// let ( x, report ) = bicgstab( &a, &b, &config ).unwrap();
+/// let ax = a.mul_vec( &x ).unwrap();
+///
+/// assert!( report.converged );
+/// for ( found, expected ) in ax.iter().zip( &b ) {
+///     assert!( ( found - expected ).abs() < 1e-6 );
+/// }
+/// ```
+///
+pub fn bicgstab<T: Float, A: LinearOperator<T>>(
+    a: &A,
+    b: &[T],
+    config: &SolverConfig<T>,
+) -> Result<(Vec<T>, ConvergenceReport<T>), MatrixError> {
+    let inverse_diagonal = prepare(a, b, config)?;
+    let n = b.len();
+    let b_norm = norm(b);
+    let mut x = vec![T::ZERO; n];
+    if b_norm == T::ZERO {
+        return Ok((
+            x,
+            ConvergenceReport {
+                iterations: 0,
+                residual: T::ZERO,
+                converged: true,
+            },
+        ));
+    }
+
+    let mut r = b.to_vec();
+    let r_hat = r.clone();
+    let (mut rho, mut alpha, mut omega) = (T::ONE, T::ONE, T::ONE);
+    let mut v = vec![T::ZERO; n];
+    let mut p = vec![T::ZERO; n];
+    let mut residual = T::ONE;
+
+    for iteration in 1..=config.max_iterations {
+        let rho_next = dot(&r_hat, &r);
+        if rho_next == T::ZERO || omega == T::ZERO {
+            return Ok((
+                x,
+                ConvergenceReport {
+                    iterations: iteration - 1,
+                    residual,
+                    converged: false,
+                },
+            ));
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        for i in 0..n {
+            p[i] = r[i] + beta * (p[i] - omega * v[i]);
+        }
+
+        let y = precondition(&inverse_diagonal, &p);
+        v = a.apply(&y)?;
+        let r_hat_v = dot(&r_hat, &v);
+        if r_hat_v == T::ZERO {
+            return Ok((
+                x,
+                ConvergenceReport {
+                    iterations: iteration - 1,
+                    residual,
+                    converged: false,
+                },
+            ));
+        }
+        alpha = rho / r_hat_v;
+        let s: Vec<T> = r.iter().zip(&v).map(|(ri, vi)| *ri - alpha * *vi).collect();
+
+        residual = norm(&s) / b_norm;
+        if residual <= config.tolerance {
+            for i in 0..n {
+                x[i] += alpha * y[i];
+            }
+            return Ok((
+                x,
+                ConvergenceReport {
+                    iterations: iteration,
+                    residual,
+                    converged: true,
+                },
+            ));
+        }
+
+        let z = precondition(&inverse_diagonal, &s);
+        let t = a.apply(&z)?;
+        let t_t = dot(&t, &t);
+        omega = if t_t == T::ZERO {
+            T::ZERO
+        } else {
+            dot(&t, &s) / t_t
+        };
+        for i in 0..n {
+            x[i] += alpha * y[i] + omega * z[i];
+            r[i] = s[i] - omega * t[i];
+        }
+
+        residual = norm(&r) / b_norm;
+        if residual <= config.tolerance {
+            return Ok((
+                x,
+                ConvergenceReport {
+                    iterations: iteration,
+                    residual,
+                    converged: true,
+                },
+            ));
+        }
+    }
+
+    Ok((
+        x,
+        ConvergenceReport {
+            iterations: config.max_iterations,
+            residual,
+            converged: false,
+        },
+    ))
+}
+
+/// Solves A * x = b for x with the restarted generalized minimal residual method, for general square A
+///
+/// A Krylov basis of at most restart vectors is built before the method restarts from the current estimate.
+/// Each basis vector counts as one iteration. The preconditioner is applied on the right
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = DMatrix::new( 3, 3, vec![
+///     4.0, 1.0, 0.0,
+///     2.0, 5.0, 1.0,
+///     0.0, -1.0, 3.0,
+/// ] ).unwrap();
+/// let b = [ 1.0, 2.0, 3.0 ];
+///
+/// let ( x, report ) = gmres( &a, &b, 2, &SolverConfig::default() ).unwrap();
+/// let expected = a.lu().unwrap().solve_vec( &b ).unwrap();
+///
+/// assert!( report.converged );
+/// for ( found, expected ) in x.iter().zip( &expected ) {
+///     assert!( ( found - expected ).abs() < 1e-6 );
+/// }
+/// ```
+///
+pub fn gmres<T: Float, A: LinearOperator<T>>(
+    a: &A,
+    b: &[T],
+    restart: usize,
+    config: &SolverConfig<T>,
+) -> Result<(Vec<T>, ConvergenceReport<T>), MatrixError> {
+    let inverse_diagonal = prepare(a, b, config)?;
+    let n = b.len();
+    let b_norm = norm(b);
+    let mut x = vec![T::ZERO; n];
+    if b_norm == T::ZERO {
+        return Ok((
+            x,
+            ConvergenceReport {
+                iterations: 0,
+                residual: T::ZERO,
+                converged: true,
+            },
+        ));
+    }
+    let restart = restart.clamp(1, n.max(1));
+
+    let mut iterations = 0;
+    loop {
+        let ax = a.apply(&x)?;
+        let r: Vec<T> = b.iter().zip(&ax).map(|(bi, axi)| *bi - *axi).collect();
+        let r_norm = norm(&r);
+        let residual = r_norm / b_norm;
+        if residual <= config.tolerance || iterations >= config.max_iterations {
+            return Ok((
+                x,
+                ConvergenceReport {
+                    iterations,
+                    residual,
+                    converged: residual <= config.tolerance,
+                },
+            ));
+        }
+
+        /* Arnoldi process with modified Gram-Schmidt, with Givens rotations keeping h upper triangular */
+        let mut basis = vec![r.iter().map(|ri| *ri / r_norm).collect::<Vec<T>>()];
+        let mut h = vec![vec![T::ZERO; restart]; restart + 1];
+        let mut rotations: Vec<(T, T)> = Vec::with_capacity(restart);
+        let mut g = vec![T::ZERO; restart + 1];
+        g[0] = r_norm;
+
+        let mut size = 0;
+        while size < restart && iterations < config.max_iterations {
+            let j = size;
+            let mut w = a.apply(&precondition(&inverse_diagonal, &basis[j]))?;
+            for (i, v) in basis.iter().enumerate() {
+                let coefficient = dot(&w, v);
+                h[i][j] = coefficient;
+                for (wk, vk) in w.iter_mut().zip(v) {
+                    *wk -= coefficient * *vk;
+                }
+            }
+            let w_norm = norm(&w);
+            h[j + 1][j] = w_norm;
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (upper, lower) = (h[i][j], h[i + 1][j]);
+                h[i][j] = c * upper + s * lower;
+                h[i + 1][j] = c * lower - s * upper;
+            }
+            let denominator = h[j][j].hypot(h[j + 1][j]);
+            let (c, s) = if denominator == T::ZERO {
+                (T::ONE, T::ZERO)
+            } else {
+                (h[j][j] / denominator, h[j + 1][j] / denominator)
+            };
+            rotations.push((c, s));
+            h[j][j] = denominator;
+            h[j + 1][j] = T::ZERO;
+            g[j + 1] = -s * g[j];
+            g[j] = c * g[j];
+
+            size += 1;
+            iterations += 1;
+            if w_norm == T::ZERO || g[j + 1].abs() / b_norm <= config.tolerance {
+                break;
+            }
+            basis.push(w.iter().map(|wi| *wi / w_norm).collect());
+        }
+
+        /* Back substitute for the basis coefficients, then map them through the preconditioner */
+        let mut y = vec![T::ZERO; size];
+        for i in (0..size).rev() {
+            let sum = ((i + 1)..size).fold(g[i], |acc, k| acc - h[i][k] * y[k]);
+            y[i] = if h[i][i] == T::ZERO {
+                T::ZERO
+            } else {
+                sum / h[i][i]
+            };
+        }
+        let mut update = vec![T::ZERO; n];
+        for (yi, v) in y.iter().zip(&basis) {
+            for (uk, vk) in update.iter_mut().zip(v) {
+                *uk += *yi * *vk;
+            }
+        }
+        for (xi, ui) in x.iter_mut().zip(precondition(&inverse_diagonal, &update)) {
+            *xi += ui;
+        }
+    }
+}
@@ -1 +1,872 @@
+mod cholesky;
+mod dynamic;
+mod eigen;
+mod generic;
+mod lu;
+mod qr;
+mod svd;
 
+pub use cholesky::{Cholesky, Ldlt};
+pub use dynamic::{DMatrix, DMatrixView};
+pub use eigen::SymmetricEigen;
+pub use generic::Matrix;
+pub use lu::Lu;
+pub use qr::{least_squares, Qr};
+pub use svd::Svd;
+
+use super::quat::Quat;
+use super::vector::{Vec2, Vec3, Vec4};
+
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+/// The ways an operation on matrices whose shape is only known at runtime can fail
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = DMatrix::new( 2, 3, vec![ 0.0; 6 ] ).unwrap();
+///
+/// assert_eq!( a.reshape( 4, 4 ).unwrap_err().to_string(), "dimension mismatch: expected 2x3, found 4x4" );
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MatrixError {
+    /// The shapes of the operands are incompatible
+    DimensionMismatch {
+        /// The shape that was required, as ( rows, columns )
+        expected: (usize, usize),
+        /// The shape that was entered, as ( rows, columns )
+        found: (usize, usize),
+    },
+    /// An index or range reaches outside of the matrix
+    OutOfBounds {
+        /// The offending index, as ( row, column )
+        index: (usize, usize),
+        /// The shape of the matrix, as ( rows, columns )
+        shape: (usize, usize),
+    },
+    /// The operation needs a square matrix
+    NotSquare {
+        /// The shape of the matrix, as ( rows, columns )
+        shape: (usize, usize),
+    },
+    /// The matrix has no inverse, so the system has no unique solution
+    Singular,
+    /// The columns of the matrix are linearly dependent
+    RankDeficient {
+        /// The numerical rank of the matrix
+        rank: usize,
+    },
+    /// The operation needs a symmetric matrix
+    NotSymmetric,
+    /// An iterative algorithm did not converge within its iteration limit
+    NoConvergence,
+    /// The operation needs a symmetric positive-definite matrix
+    NotPositiveDefinite,
+}
+
+/// Implements Display for MatrixError
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::DimensionMismatch { expected, found } => write!(
+                f,
+                "dimension mismatch: expected {}x{}, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            MatrixError::OutOfBounds { index, shape } => write!(
+                f,
+                "index ({}, {}) out of bounds for a {}x{} matrix",
+                index.0, index.1, shape.0, shape.1
+            ),
+            MatrixError::NotSquare { shape } => {
+                write!(f, "expected a square matrix, found {}x{}", shape.0, shape.1)
+            }
+            MatrixError::Singular => write!(f, "matrix is singular"),
+            MatrixError::RankDeficient { rank } => {
+                write!(f, "matrix is rank deficient, its rank is {}", rank)
+            }
+            MatrixError::NotSymmetric => write!(f, "expected a symmetric matrix"),
+            MatrixError::NoConvergence => write!(f, "iteration did not converge"),
+            MatrixError::NotPositiveDefinite => {
+                write!(f, "expected a symmetric positive-definite matrix")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
+/* Implements the operators shared by every fixed size square matrix, the elementwise ones work on the rows array directly */
+macro_rules! impl_mat_ops {
+    ($mat:ident, $n:literal) => {
+        /// Implements Default for the matrix
+        /// Generates a new instance initialized to zero and returns it
+        impl Default for $mat {
+            fn default() -> Self {
+                Self::zero()
+            }
+        }
+
+        /// Implements Add for the matrix, adding each element of rhs to the corresponding element of self
+        impl Add for $mat {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                let mut m = self.m;
+                for (row, rhs_row) in m.iter_mut().zip(rhs.m.iter()) {
+                    for (elem, rhs_elem) in row.iter_mut().zip(rhs_row.iter()) {
+                        *elem += rhs_elem;
+                    }
+                }
+                Self { m }
+            }
+        }
+
+        /// Implements AddAssign for the matrix
+        impl AddAssign for $mat {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        /// Implements Sub for the matrix, subtracting each element of rhs from the corresponding element of self
+        impl Sub for $mat {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                let mut m = self.m;
+                for (row, rhs_row) in m.iter_mut().zip(rhs.m.iter()) {
+                    for (elem, rhs_elem) in row.iter_mut().zip(rhs_row.iter()) {
+                        *elem -= rhs_elem;
+                    }
+                }
+                Self { m }
+            }
+        }
+
+        /// Implements SubAssign for the matrix
+        impl SubAssign for $mat {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        /// Implements Mul for the matrix, this is the matrix product and not an elementwise product
+        impl Mul for $mat {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                let mut m = [[0.0; $n]; $n];
+                for (i, row) in m.iter_mut().enumerate() {
+                    for (j, elem) in row.iter_mut().enumerate() {
+                        *elem = (0..$n).map(|k| self.m[i][k] * rhs.m[k][j]).sum();
+                    }
+                }
+                Self { m }
+            }
+        }
+
+        /// Implements MulAssign for the matrix
+        impl MulAssign for $mat {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        /// Implements Mul by a scalar for the matrix
+        impl Mul<f32> for $mat {
+            type Output = Self;
+
+            fn mul(self, rhs: f32) -> Self::Output {
+                let mut m = self.m;
+                m.iter_mut().flatten().for_each(|elem| *elem *= rhs);
+                Self { m }
+            }
+        }
+
+        /// Implements MulAssign by a scalar for the matrix
+        impl MulAssign<f32> for $mat {
+            fn mul_assign(&mut self, rhs: f32) {
+                *self = *self * rhs;
+            }
+        }
+
+        /// Implements Div by a scalar for the matrix
+        impl Div<f32> for $mat {
+            type Output = Self;
+
+            fn div(self, rhs: f32) -> Self::Output {
+                let mut m = self.m;
+                m.iter_mut().flatten().for_each(|elem| *elem /= rhs);
+                Self { m }
+            }
+        }
+
+        /// Implements DivAssign by a scalar for the matrix
+        impl DivAssign<f32> for $mat {
+            fn div_assign(&mut self, rhs: f32) {
+                *self = *self / rhs;
+            }
+        }
+
+        /// Implements Neg for the matrix
+        impl Neg for $mat {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                self * -1.0
+            }
+        }
+
+        /// Implements Index for the matrix, indexed by ( row, column )
+        impl Index<(usize, usize)> for $mat {
+            type Output = f32;
+
+            fn index(&self, index: (usize, usize)) -> &Self::Output {
+                &self.m[index.0][index.1]
+            }
+        }
+
+        /// Implements IndexMut for the matrix, indexed by ( row, column )
+        impl IndexMut<(usize, usize)> for $mat {
+            fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+                &mut self.m[index.0][index.1]
+            }
+        }
+
+        /// Implements PartialEq for the matrix, elements are compared within a tolerance
+        impl PartialEq for $mat {
+            fn eq(&self, rhs: &Self) -> bool {
+                self.m
+                    .iter()
+                    .flatten()
+                    .zip(rhs.m.iter().flatten())
+                    .all(|(a, b)| (a - b).abs() < 0.00001)
+            }
+        }
+
+        impl $mat {
+            /// Generates a new instance initialized to zero and returns it
+            pub fn zero() -> $mat {
+                $mat { m: [[0.0; $n]; $n] }
+            }
+
+            /// Generates a new identity matrix and returns it
+            pub fn identity() -> $mat {
+                let mut m = [[0.0; $n]; $n];
+                for (i, row) in m.iter_mut().enumerate() {
+                    row[i] = 1.0;
+                }
+                $mat { m }
+            }
+
+            /// Generates a new instance from an array of rows and returns it
+            pub fn from_rows(rows: &[[f32; $n]; $n]) -> $mat {
+                $mat { m: *rows }
+            }
+
+            /// Generates a new instance from an array of columns and returns it
+            pub fn from_cols(cols: &[[f32; $n]; $n]) -> $mat {
+                $mat { m: *cols }.transpose()
+            }
+
+            /// Returns a copy of the row at index i
+            pub fn row(&self, i: usize) -> [f32; $n] {
+                self.m[i]
+            }
+
+            /// Returns a copy of the column at index j
+            pub fn col(&self, j: usize) -> [f32; $n] {
+                let mut col = [0.0; $n];
+                for (i, elem) in col.iter_mut().enumerate() {
+                    *elem = self.m[i][j];
+                }
+                col
+            }
+
+            /// Calculates the sum of the diagonal elements of self
+            pub fn trace(&self) -> f32 {
+                (0..$n).map(|i| self.m[i][i]).sum()
+            }
+
+            /// Generates the transpose of self and returns it
+            pub fn transpose(&self) -> $mat {
+                let mut m = [[0.0; $n]; $n];
+                for (i, row) in m.iter_mut().enumerate() {
+                    for (j, elem) in row.iter_mut().enumerate() {
+                        *elem = self.m[j][i];
+                    }
+                }
+                $mat { m }
+            }
+
+            /// Calculates the inverse of self, returns None if self is singular
+            pub fn inverse(&self) -> Option<$mat> {
+                /* Only an exact zero is singular, a tolerance would reject uniformly scaled down matrices */
+                let det = self.determinant();
+                if det == 0.0 || !det.is_finite() {
+                    None
+                } else {
+                    Some(self.adjugate() / det)
+                }
+            }
+        }
+    };
+}
+
+/// A 2x2 matrix of f32, stored as an array of rows
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = Mat2::from_rows( &[ [ 1.0, 2.0 ], [ 3.0, 4.0 ] ] );
+///
+/// assert_eq!( a[ ( 1, 0 ) ], 3.0 );
+/// assert_eq!( a * Mat2::identity(), a );
+/// ```
+///
+#[derive(Copy, Clone, Debug)]
+pub struct Mat2 {
+    /// The elements of the matrix, indexed as m[ row ][ column ]
+    pub m: [[f32; 2]; 2],
+}
+
+impl_mat_ops!(Mat2, 2);
+
+/// Implements Mul of a Vec2 by Mat2, treating the Vec2 as a column vector
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let rot = Mat2::from_rows( &[ [ 0.0, -1.0 ], [ 1.0, 0.0 ] ] );
+/// let a = Vec2::new( &1.0, &0.0 );
+///
+/// assert_eq!( rot * a, Vec2::new( &0.0, &1.0 ) );
+/// ```
+///
+impl Mul<Vec2> for Mat2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        Vec2::new(
+            &(self.m[0][0] * rhs.x + self.m[0][1] * rhs.y),
+            &(self.m[1][0] * rhs.x + self.m[1][1] * rhs.y),
+        )
+    }
+}
+
+impl Mat2 {
+    /// Calculates the determinant of self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Mat2::from_rows( &[ [ 1.0, 2.0 ], [ 3.0, 4.0 ] ] );
+    ///
+    /// assert!( ( a.determinant() + 2.0 ).abs() < 0.00001 );
+    /// ```
+    ///
+    pub fn determinant(&self) -> f32 {
+        self.m[0][0] * self.m[1][1] - self.m[0][1] * self.m[1][0]
+    }
+
+    /* The transpose of the cofactor matrix, used to build the inverse */
+    fn adjugate(&self) -> Mat2 {
+        Mat2 {
+            m: [[self.m[1][1], -self.m[0][1]], [-self.m[1][0], self.m[0][0]]],
+        }
+    }
+}
+
+/// A 3x3 matrix of f32, stored as an array of rows
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = Mat3::from_rows( &[ [ 2.0, 0.0, 0.0 ], [ 0.0, 3.0, 0.0 ], [ 0.0, 0.0, 4.0 ] ] );
+/// let b = a.inverse().unwrap();
+///
+/// assert_eq!( a * b, Mat3::identity() );
+/// ```
+///
+#[derive(Copy, Clone, Debug)]
+pub struct Mat3 {
+    /// The elements of the matrix, indexed as m[ row ][ column ]
+    pub m: [[f32; 3]; 3],
+}
+
+impl_mat_ops!(Mat3, 3);
+
+/// Implements Mul of a Vec3 by Mat3, treating the Vec3 as a column vector
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let scale = Mat3::from_rows( &[ [ 2.0, 0.0, 0.0 ], [ 0.0, 3.0, 0.0 ], [ 0.0, 0.0, 4.0 ] ] );
+/// let a = Vec3::new( &1.0, &1.0, &1.0 );
+///
+/// assert_eq!( scale * a, Vec3::new( &2.0, &3.0, &4.0 ) );
+/// ```
+///
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        let m = &self.m;
+        Vec3::new(
+            &(m[0][0] * rhs.x + m[0][1] * rhs.y + m[0][2] * rhs.z),
+            &(m[1][0] * rhs.x + m[1][1] * rhs.y + m[1][2] * rhs.z),
+            &(m[2][0] * rhs.x + m[2][1] * rhs.y + m[2][2] * rhs.z),
+        )
+    }
+}
+
+impl Mat3 {
+    /// Calculates the determinant of self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Mat3::from_rows( &[ [ 1.0, 2.0, 3.0 ], [ 0.0, 1.0, 4.0 ], [ 5.0, 6.0, 0.0 ] ] );
+    ///
+    /// assert!( ( a.determinant() - 1.0 ).abs() < 0.00001 );
+    /// ```
+    ///
+    pub fn determinant(&self) -> f32 {
+        let m = &self.m;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /* The transpose of the cofactor matrix, used to build the inverse */
+    fn adjugate(&self) -> Mat3 {
+        let m = &self.m;
+        let mut adj = [[0.0; 3]; 3];
+        for (i, row) in adj.iter_mut().enumerate() {
+            for (j, elem) in row.iter_mut().enumerate() {
+                /* Cofactor of element ( j, i ), the cyclic indices take care of the sign */
+                let (r0, r1) = ((j + 1) % 3, (j + 2) % 3);
+                let (c0, c1) = ((i + 1) % 3, (i + 2) % 3);
+                *elem = m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+            }
+        }
+        Mat3 { m: adj }
+    }
+}
+
+/// A 4x4 matrix of f32, stored as an array of rows
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let mut a = Mat4::identity();
+/// a[ ( 0, 3 ) ] = 5.0;
+///
+/// assert_eq!( a * Vec3::new( &1.0, &2.0, &3.0 ), Vec3::new( &6.0, &2.0, &3.0 ) );
+///
+/// let b = Mat4::from_rows( &[
+///     [ 1.0, 0.0, 2.0, -1.0 ],
+///     [ 3.0, 0.0, 0.0, 5.0 ],
+///     [ 2.0, 1.0, 4.0, -3.0 ],
+///     [ 1.0, 0.0, 5.0, 0.0 ],
+/// ] );
+///
+/// assert_eq!( b * b.inverse().unwrap(), Mat4::identity() );
+/// assert_eq!( b.transpose().transpose(), b );
+/// assert!( Mat4::zero().inverse().is_none() );
+/// ```
+///
+#[derive(Copy, Clone, Debug)]
+pub struct Mat4 {
+    /// The elements of the matrix, indexed as m[ row ][ column ]
+    pub m: [[f32; 4]; 4],
+}
+
+impl_mat_ops!(Mat4, 4);
+
+/// Implements Mul of a Vec3 by Mat4, treating the Vec3 as a point with a w of 1
+///
+/// The result is always divided by the resulting w. A w of 0 means the point is at infinity,
+/// and gives infinite or NaN components, use Mat4 * Vec4 to keep the homogeneous form instead
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let scale = Mat4::from_rows( &[
+///     [ 2.0, 0.0, 0.0, 0.0 ],
+///     [ 0.0, 2.0, 0.0, 0.0 ],
+///     [ 0.0, 0.0, 2.0, 0.0 ],
+///     [ 0.0, 0.0, 0.0, 1.0 ],
+/// ] );
+///
+/// assert_eq!( scale * Vec3::new( &1.0, &2.0, &3.0 ), Vec3::new( &2.0, &4.0, &6.0 ) );
+/// ```
+///
+impl Mul<Vec3> for Mat4 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        let m = &self.m;
+        let x = m[0][0] * rhs.x + m[0][1] * rhs.y + m[0][2] * rhs.z + m[0][3];
+        let y = m[1][0] * rhs.x + m[1][1] * rhs.y + m[1][2] * rhs.z + m[1][3];
+        let z = m[2][0] * rhs.x + m[2][1] * rhs.y + m[2][2] * rhs.z + m[2][3];
+        let w = m[3][0] * rhs.x + m[3][1] * rhs.y + m[3][2] * rhs.z + m[3][3];
+
+        Vec3::new(&(x / w), &(y / w), &(z / w))
+    }
+}
+
+/// Implements Mul of a Vec4 by Mat4, treating the Vec4 as a homogeneous column vector
+///
+/// No perspective divide is applied, see Vec4::perspective_divide
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let mut translate = Mat4::identity();
+/// translate[ ( 0, 3 ) ] = 5.0;
+///
+/// let point = Vec4::new( &1.0, &2.0, &3.0, &1.0 );
+/// let direction = Vec4::new( &1.0, &2.0, &3.0, &0.0 );
+///
+/// assert_eq!( translate * point, Vec4::new( &6.0, &2.0, &3.0, &1.0 ) );
+/// assert_eq!( translate * direction, direction );
+/// ```
+///
+impl Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Self::Output {
+        let row = |r: [f32; 4]| r[0] * rhs.x + r[1] * rhs.y + r[2] * rhs.z + r[3] * rhs.w;
+        Vec4::new(
+            &row(self.m[0]),
+            &row(self.m[1]),
+            &row(self.m[2]),
+            &row(self.m[3]),
+        )
+    }
+}
+
+impl Mat4 {
+    /// Generates a new transform which translates points by the entered offset and returns it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Mat4::from_translation( &Vec3::new( &1.0, &2.0, &3.0 ) );
+    /// let b = Mat4::from_scale( &Vec3::new( &2.0, &2.0, &2.0 ) );
+    ///
+    /// // b is applied first
+    /// assert_eq!( a * b * Vec3::new( &1.0, &1.0, &1.0 ), Vec3::new( &3.0, &4.0, &5.0 ) );
+    /// ```
+    ///
+    pub fn from_translation(offset: &Vec3) -> Mat4 {
+        Mat4::from_rows(&[
+            [1.0, 0.0, 0.0, offset.x],
+            [0.0, 1.0, 0.0, offset.y],
+            [0.0, 0.0, 1.0, offset.z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Generates a new transform which scales each axis by the entered factors and returns it
+    pub fn from_scale(factors: &Vec3) -> Mat4 {
+        Mat4::from_rows(&[
+            [factors.x, 0.0, 0.0, 0.0],
+            [0.0, factors.y, 0.0, 0.0],
+            [0.0, 0.0, factors.z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Generates a new transform which applies the rotation of the entered quaternion and returns it
+    pub fn from_quat(rotation: &Quat) -> Mat4 {
+        rotation.to_mat4()
+    }
+
+    /// Generates a right handed view matrix for a camera at eye looking at target, with the camera
+    /// looking down its -z axis
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let eye = Vec3::new( &0.0, &0.0, &5.0 );
+    /// let view = Mat4::look_at_rh( &eye, &Vec3::default(), &Vec3::new( &0.0, &1.0, &0.0 ) );
+    ///
+    /// assert_eq!( view * Vec3::default(), Vec3::new( &0.0, &0.0, &-5.0 ) );
+    /// assert_eq!( view * Vec3::new( &1.0, &0.0, &0.0 ), Vec3::new( &1.0, &0.0, &-5.0 ) );
+    /// ```
+    ///
+    pub fn look_at_rh(eye: &Vec3, target: &Vec3, up: &Vec3) -> Mat4 {
+        Mat4::look_to(
+            eye,
+            &[eye.x - target.x, eye.y - target.y, eye.z - target.z],
+            up,
+        )
+    }
+
+    /// Generates a left handed view matrix for a camera at eye looking at target, with the camera
+    /// looking down its +z axis
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let eye = Vec3::new( &0.0, &0.0, &-5.0 );
+    /// let view = Mat4::look_at_lh( &eye, &Vec3::default(), &Vec3::new( &0.0, &1.0, &0.0 ) );
+    ///
+    /// assert_eq!( view * Vec3::default(), Vec3::new( &0.0, &0.0, &5.0 ) );
+    /// assert_eq!( view * Vec3::new( &1.0, &0.0, &0.0 ), Vec3::new( &1.0, &0.0, &5.0 ) );
+    /// ```
+    ///
+    pub fn look_at_lh(eye: &Vec3, target: &Vec3, up: &Vec3) -> Mat4 {
+        Mat4::look_to(
+            eye,
+            &[target.x - eye.x, target.y - eye.y, target.z - eye.z],
+            up,
+        )
+    }
+
+    /* The view matrix with its z axis along back, shared by the right and left handed look_at.
+    Passing the forward direction instead gives the left handed basis with x still to the right */
+    fn look_to(eye: &Vec3, back: &[f32; 3], up: &Vec3) -> Mat4 {
+        let normalize = |v: [f32; 3]| {
+            let mag = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+            if mag == 0.0 {
+                v
+            } else {
+                [v[0] / mag, v[1] / mag, v[2] / mag]
+            }
+        };
+        let cross = |a: [f32; 3], b: [f32; 3]| {
+            [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ]
+        };
+        let dot = |a: [f32; 3]| a[0] * eye.x + a[1] * eye.y + a[2] * eye.z;
+
+        let z = normalize(*back);
+        let x = normalize(cross([up.x, up.y, up.z], z));
+        let y = cross(z, x);
+
+        Mat4::from_rows(&[
+            [x[0], x[1], x[2], -dot(x)],
+            [y[0], y[1], y[2], -dot(y)],
+            [z[0], z[1], z[2], -dot(z)],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Generates a right handed perspective projection, mapping view space to normalized device
+    /// coordinates with depth in [-1,1], as OpenGL does
+    ///
+    /// fov_y is the vertical field of view in radians and aspect is width over height
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use miscmath::prelude::*;
+    ///
+    /// let proj = Mat4::perspective( &FRAC_PI_2, &2.0, &1.0, &100.0 );
+    ///
+    /// assert_eq!( proj.project( &Vec3::new( &0.0, &0.0, &-1.0 ) ), Vec3::new( &0.0, &0.0, &-1.0 ) );
+    /// assert_eq!( proj.project( &Vec3::new( &0.0, &0.0, &-100.0 ) ), Vec3::new( &0.0, &0.0, &1.0 ) );
+    ///
+    /// // A 90 degree field of view puts the top edge at y == -z
+    /// assert_eq!( proj.project( &Vec3::new( &20.0, &10.0, &-10.0 ) ).y, 1.0 );
+    /// assert_eq!( proj.project( &Vec3::new( &20.0, &10.0, &-10.0 ) ).x, 1.0 );
+    /// ```
+    ///
+    pub fn perspective(fov_y: &f32, aspect: &f32, near: &f32, far: &f32) -> Mat4 {
+        let f = 1.0 / (*fov_y / 2.0).tan();
+        let depth = near - far;
+
+        Mat4::from_rows(&[
+            [f / aspect, 0.0, 0.0, 0.0],
+            [0.0, f, 0.0, 0.0],
+            [0.0, 0.0, (far + near) / depth, 2.0 * far * near / depth],
+            [0.0, 0.0, -1.0, 0.0],
+        ])
+    }
+
+    /// Generates a right handed orthographic projection, mapping the box bounded by the entered
+    /// planes to normalized device coordinates with depth in [-1,1]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let proj = Mat4::orthographic( &0.0, &800.0, &0.0, &600.0, &0.1, &10.0 );
+    ///
+    /// assert_eq!( proj * Vec3::new( &400.0, &600.0, &-0.1 ), Vec3::new( &0.0, &1.0, &-1.0 ) );
+    /// assert_eq!( proj * Vec3::new( &0.0, &0.0, &-10.0 ), Vec3::new( &-1.0, &-1.0, &1.0 ) );
+    /// ```
+    ///
+    pub fn orthographic(
+        left: &f32,
+        right: &f32,
+        bottom: &f32,
+        top: &f32,
+        near: &f32,
+        far: &f32,
+    ) -> Mat4 {
+        let (width, height, depth) = (right - left, top - bottom, far - near);
+
+        Mat4::from_rows(&[
+            [2.0 / width, 0.0, 0.0, -(right + left) / width],
+            [0.0, 2.0 / height, 0.0, -(top + bottom) / height],
+            [0.0, 0.0, -2.0 / depth, -(far + near) / depth],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Transforms a world space point by self, a combined view projection matrix, into normalized
+    /// device coordinates, applying the perspective divide
+    pub fn project(&self, point: &Vec3) -> Vec3 {
+        (*self * Vec4::from_vec3(point, &1.0)).perspective_divide()
+    }
+
+    /// Transforms a point in normalized device coordinates back into world space, the inverse of
+    /// project. Returns None if self is singular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let view = Mat4::look_at_rh(
+    ///     &Vec3::new( &3.0, &4.0, &5.0 ),
+    ///     &Vec3::default(),
+    ///     &Vec3::new( &0.0, &1.0, &0.0 ),
+    /// );
+    /// let view_proj = Mat4::perspective( &1.0, &1.5, &0.5, &50.0 ) * view;
+    ///
+    /// let point = Vec3::new( &0.5, &-1.0, &0.25 );
+    /// let ndc = view_proj.project( &point );
+    ///
+    /// assert!( ndc.x.abs() <= 1.0 && ndc.y.abs() <= 1.0 && ndc.z.abs() <= 1.0 );
+    /// assert!( ( view_proj.unproject( &ndc ).unwrap() - point ).mag() < 0.0001 );
+    ///
+    /// // Small scales are not mistaken for singular matrices
+    /// let small = Mat4::from_scale( &Vec3::new( &0.001, &0.001, &0.001 ) );
+    ///
+    /// assert!( ( small.unproject( &Vec3::new( &0.001, &0.002, &0.003 ) ).unwrap() - Vec3::new( &1.0, &2.0, &3.0 ) ).mag() < 0.0001 );
+    /// assert_eq!( Mat4::from_scale( &Vec3::new( &1.0, &0.0, &1.0 ) ).unproject( &Vec3::default() ), None );
+    /// ```
+    ///
+    pub fn unproject(&self, ndc: &Vec3) -> Option<Vec3> {
+        self.inverse().map(|inv| inv.project(ndc))
+    }
+
+    /// Calculates the determinant of self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Mat4::from_rows( &[
+    ///     [ 1.0, 0.0, 2.0, -1.0 ],
+    ///     [ 3.0, 0.0, 0.0, 5.0 ],
+    ///     [ 2.0, 1.0, 4.0, -3.0 ],
+    ///     [ 1.0, 0.0, 5.0, 0.0 ],
+    /// ] );
+    ///
+    /// assert!( ( a.determinant() - 30.0 ).abs() < 0.0001 );
+    /// ```
+    ///
+    pub fn determinant(&self) -> f32 {
+        let (s, c) = self.sub_determinants();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /* The 2x2 determinants of the top two rows (s) and bottom two rows (c), shared by the determinant and adjugate */
+    fn sub_determinants(&self) -> ([f32; 6], [f32; 6]) {
+        let m = &self.m;
+        let s = [
+            m[0][0] * m[1][1] - m[1][0] * m[0][1],
+            m[0][0] * m[1][2] - m[1][0] * m[0][2],
+            m[0][0] * m[1][3] - m[1][0] * m[0][3],
+            m[0][1] * m[1][2] - m[1][1] * m[0][2],
+            m[0][1] * m[1][3] - m[1][1] * m[0][3],
+            m[0][2] * m[1][3] - m[1][2] * m[0][3],
+        ];
+        let c = [
+            m[2][0] * m[3][1] - m[3][0] * m[2][1],
+            m[2][0] * m[3][2] - m[3][0] * m[2][2],
+            m[2][0] * m[3][3] - m[3][0] * m[2][3],
+            m[2][1] * m[3][2] - m[3][1] * m[2][2],
+            m[2][1] * m[3][3] - m[3][1] * m[2][3],
+            m[2][2] * m[3][3] - m[3][2] * m[2][3],
+        ];
+        (s, c)
+    }
+
+    /* The transpose of the cofactor matrix, used to build the inverse */
+    fn adjugate(&self) -> Mat4 {
+        let m = &self.m;
+        let (s, c) = self.sub_determinants();
+        Mat4 {
+            m: [
+                [
+                    m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+                    -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+                    m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+                    -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3],
+                ],
+                [
+                    -m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+                    m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+                    -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+                    m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1],
+                ],
+                [
+                    m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+                    -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+                    m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+                    -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0],
+                ],
+                [
+                    -m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+                    m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+                    -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+                    m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0],
+                ],
+            ],
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use super::{DMatrix, MatrixError};
+use crate::linear::scalar::Float;
+
+/// The Cholesky decomposition of a symmetric positive-definite matrix, such that A = L * L^T
+///
+/// L is lower triangular with a positive diagonal
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = DMatrix::new( 3, 3, vec![ 4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0 ] ).unwrap();
+/// let chol = a.cholesky().unwrap();
+///
+/// assert_eq!( chol.l().as_slice(), &[ 2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0 ] );
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Cholesky<T> {
+    /// The lower triangular factor, the upper triangle is left as zero
+    l: DMatrix<T>,
+}
+
+/// The LDL^T decomposition of a symmetric matrix, such that A = L * D * L^T
+///
+/// L is unit lower triangular and D is diagonal. Unlike Cholesky this needs no square roots and
+/// also works for symmetric matrices which are indefinite, as long as no leading minor is singular
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = DMatrix::new( 2, 2, vec![ 1.0, 2.0, 2.0, 1.0 ] ).unwrap();
+/// let ldlt = a.ldlt().unwrap();
+///
+/// assert_eq!( ldlt.d(), &[ 1.0, -3.0 ] );
+/// assert!( !ldlt.is_positive_definite() );
+/// assert!( a.cholesky().is_err() );
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Ldlt<T> {
+    /// The unit lower triangular factor, its diagonal is stored as ones
+    l: DMatrix<T>,
+    /// The diagonal of D
+    d: Vec<T>,
+}
+
+impl<T: Float> DMatrix<T> {
+    /// Calculates the Cholesky decomposition of self, which must be symmetric positive-definite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 2, vec![ 1.0, 2.0, 2.0, 1.0 ] ).unwrap();
+    ///
+    /// assert_eq!( a.cholesky().unwrap_err(), MatrixError::NotPositiveDefinite );
+    ///
+    /// // Singular, even though rounding leaves a tiny positive pivot
+    /// let b = DMatrix::new( 2, 2, vec![ 0.01, 0.01, 0.01, 0.01 ] ).unwrap();
+    ///
+    /// assert_eq!( b.cholesky().unwrap_err(), MatrixError::NotPositiveDefinite );
+    /// ```
+    ///
+    pub fn cholesky(&self) -> Result<Cholesky<T>, MatrixError> {
+        self.check_symmetric()?;
+
+        let n = self.nrows();
+        let tolerance = self.pivot_tolerance();
+        let mut l = DMatrix::zeros(n, n);
+        for j in 0..n {
+            let diag = (0..j).fold(self[(j, j)], |acc, k| acc - l[(j, k)] * l[(j, k)]);
+            if diag <= tolerance || !diag.is_finite() {
+                return Err(MatrixError::NotPositiveDefinite);
+            }
+            let ljj = diag.sqrt();
+            l[(j, j)] = ljj;
+
+            for i in (j + 1)..n {
+                let sum = (0..j).fold(self[(i, j)], |acc, k| acc - l[(i, k)] * l[(j, k)]);
+                l[(i, j)] = sum / ljj;
+            }
+        }
+        Ok(Cholesky { l })
+    }
+
+    /// Returns true if self is symmetric positive-definite, found by attempting a Cholesky decomposition
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 2, vec![ 2.0, -1.0, -1.0, 2.0 ] ).unwrap();
+    /// let b = DMatrix::new( 2, 2, vec![ 1.0, 0.0, 0.0, 0.0 ] ).unwrap();
+    ///
+    /// assert!( a.is_positive_definite() );
+    /// assert!( !b.is_positive_definite() );
+    /// ```
+    ///
+    pub fn is_positive_definite(&self) -> bool {
+        self.cholesky().is_ok()
+    }
+
+    /// Calculates the LDL^T decomposition of self, which must be symmetric
+    ///
+    /// Fails with Singular when a zero pivot is met, as no pivoting is performed
+    pub fn ldlt(&self) -> Result<Ldlt<T>, MatrixError> {
+        self.check_symmetric()?;
+
+        let n = self.nrows();
+        let tolerance = self.pivot_tolerance();
+
+        let mut l = DMatrix::identity(n);
+        let mut d = vec![T::ZERO; n];
+        for j in 0..n {
+            let dj = (0..j).fold(self[(j, j)], |acc, k| acc - l[(j, k)] * l[(j, k)] * d[k]);
+            if dj.abs() <= tolerance {
+                return Err(MatrixError::Singular);
+            }
+            d[j] = dj;
+
+            for i in (j + 1)..n {
+                let sum = (0..j).fold(self[(i, j)], |acc, k| acc - l[(i, k)] * l[(j, k)] * d[k]);
+                l[(i, j)] = sum / dj;
+            }
+        }
+        Ok(Ldlt { l, d })
+    }
+
+    /* Pivots at or below this are treated as zero by both decompositions, scaled by the size and largest element of self */
+    fn pivot_tolerance(&self) -> T {
+        let max_abs = self
+            .as_slice()
+            .iter()
+            .fold(T::ZERO, |acc, x| acc.max(x.abs()));
+        T::EPSILON * T::from_f64(self.nrows() as f64) * max_abs
+    }
+}
+
+/* Solves L * x = b in place for lower triangular L, dividing by the diagonal unless it is a unit diagonal */
+fn forward_substitute<T: Float>(l: &DMatrix<T>, x: &mut DMatrix<T>, unit: bool) {
+    let n = l.nrows();
+    for col in 0..x.ncols() {
+        for i in 0..n {
+            let sum = (0..i).fold(x[(i, col)], |acc, k| acc - l[(i, k)] * x[(k, col)]);
+            x[(i, col)] = if unit { sum } else { sum / l[(i, i)] };
+        }
+    }
+}
+
+/* Solves L^T * x = b in place for lower triangular L, dividing by the diagonal unless it is a unit diagonal */
+fn back_substitute_transposed<T: Float>(l: &DMatrix<T>, x: &mut DMatrix<T>, unit: bool) {
+    let n = l.nrows();
+    for col in 0..x.ncols() {
+        for i in (0..n).rev() {
+            let sum = ((i + 1)..n).fold(x[(i, col)], |acc, k| acc - l[(k, i)] * x[(k, col)]);
+            x[(i, col)] = if unit { sum } else { sum / l[(i, i)] };
+        }
+    }
+}
+
+/* Checks that b has one row per row of the decomposed n x n matrix */
+fn check_rhs<T: Float>(n: usize, b: &DMatrix<T>) -> Result<(), MatrixError> {
+    if b.nrows() != n {
+        return Err(MatrixError::DimensionMismatch {
+            expected: (n, b.ncols()),
+            found: b.shape(),
+        });
+    }
+    Ok(())
+}
+
+impl<T: Float> Cholesky<T> {
+    /// Returns the lower triangular factor L
+    pub fn l(&self) -> DMatrix<T> {
+        self.l.clone()
+    }
+
+    /// Solves A * x = b for x, where b has one column per right hand side
+    pub fn solve(&self, b: &DMatrix<T>) -> Result<DMatrix<T>, MatrixError> {
+        check_rhs(self.l.nrows(), b)?;
+        let mut x = b.clone();
+        forward_substitute(&self.l, &mut x, false);
+        back_substitute_transposed(&self.l, &mut x, false);
+        Ok(x)
+    }
+
+    /// Solves A * x = b for x, where b is a single right hand side vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// // 4x + 2y = 10, 2x + 3y = 9
+    /// let a = DMatrix::new( 2, 2, vec![ 4.0, 2.0, 2.0, 3.0 ] ).unwrap();
+    /// let x = a.cholesky().unwrap().solve_vec( &[ 10.0, 9.0 ] ).unwrap();
+    ///
+    /// assert!( ( x[ 0 ] - 1.5_f64 ).abs() < 1e-12 );
+    /// assert!( ( x[ 1 ] - 2.0_f64 ).abs() < 1e-12 );
+    /// ```
+    ///
+    pub fn solve_vec(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        self.solve(&DMatrix::from_column(b)).map(DMatrix::into_vec)
+    }
+
+    /// Calculates the determinant of the decomposed matrix, the square of the product of the diagonal of L
+    pub fn determinant(&self) -> T {
+        let product = (0..self.l.nrows()).fold(T::ONE, |acc, i| acc * self.l[(i, i)]);
+        product * product
+    }
+
+    /// Calculates the inverse of the decomposed matrix
+    pub fn inverse(&self) -> DMatrix<T> {
+        let mut x = DMatrix::identity(self.l.nrows());
+        forward_substitute(&self.l, &mut x, false);
+        back_substitute_transposed(&self.l, &mut x, false);
+        x
+    }
+}
+
+impl<T: Float> Ldlt<T> {
+    /// Returns the unit lower triangular factor L
+    pub fn l(&self) -> DMatrix<T> {
+        self.l.clone()
+    }
+
+    /// Returns the diagonal of D
+    pub fn d(&self) -> &[T] {
+        &self.d
+    }
+
+    /// Returns true if every element of D is positive, which happens exactly when the matrix is positive-definite
+    pub fn is_positive_definite(&self) -> bool {
+        self.d.iter().all(|d| *d > T::ZERO)
+    }
+
+    /// Solves A * x = b for x, where b has one column per right hand side
+    pub fn solve(&self, b: &DMatrix<T>) -> Result<DMatrix<T>, MatrixError> {
+        check_rhs(self.l.nrows(), b)?;
+        let mut x = b.clone();
+        forward_substitute(&self.l, &mut x, true);
+        for i in 0..x.nrows() {
+            for col in 0..x.ncols() {
+                x[(i, col)] /= self.d[i];
+            }
+        }
+        back_substitute_transposed(&self.l, &mut x, true);
+        Ok(x)
+    }
+
+    /// Solves A * x = b for x, where b is a single right hand side vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// // x + 2y = 5, 2x + y = 4, which is symmetric but indefinite
+    /// let a = DMatrix::new( 2, 2, vec![ 1.0, 2.0, 2.0, 1.0 ] ).unwrap();
+    /// let x = a.ldlt().unwrap().solve_vec( &[ 5.0, 4.0 ] ).unwrap();
+    ///
+    /// assert!( ( x[ 0 ] - 1.0_f64 ).abs() < 1e-12 );
+    /// assert!( ( x[ 1 ] - 2.0_f64 ).abs() < 1e-12 );
+    /// ```
+    ///
+    pub fn solve_vec(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        self.solve(&DMatrix::from_column(b)).map(DMatrix::into_vec)
+    }
+
+    /// Calculates the determinant of the decomposed matrix, the product of D
+    pub fn determinant(&self) -> T {
+        self.d.iter().fold(T::ONE, |acc, d| acc * *d)
+    }
+}
@@ -0,0 +1,708 @@
+use super::{Mat2, Mat3, Mat4, Matrix, MatrixError};
+use crate::linear::scalar::{Float, Scalar};
+
+use std::ops::{Index, IndexMut, Mul, Neg, Range};
+
+/// A heap allocated matrix whose shape is only known at runtime, stored in row major order
+///
+/// Operations between two DMatrix check their shapes and return a MatrixError instead of panicking
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = DMatrix::new( 2, 3, vec![ 1, 2, 3, 4, 5, 6 ] ).unwrap();
+/// let b = DMatrix::new( 3, 1, vec![ 1, 0, 1 ] ).unwrap();
+///
+/// assert_eq!( a.try_mul( &b ).unwrap().as_slice(), &[ 4, 10 ] );
+/// assert!( b.try_mul( &b ).is_err() );
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct DMatrix<T> {
+    /// The number of rows
+    rows: usize,
+    /// The number of columns
+    cols: usize,
+    /// The elements, row after row
+    data: Vec<T>,
+}
+
+/// Implements Index for DMatrix, indexed by ( row, column )
+impl<T> Index<(usize, usize)> for DMatrix<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        assert!(index.1 < self.cols, "column index out of bounds");
+        &self.data[index.0 * self.cols + index.1]
+    }
+}
+
+/// Implements IndexMut for DMatrix, indexed by ( row, column )
+impl<T> IndexMut<(usize, usize)> for DMatrix<T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        assert!(index.1 < self.cols, "column index out of bounds");
+        &mut self.data[index.0 * self.cols + index.1]
+    }
+}
+
+/// Implements Mul by a scalar for DMatrix
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = DMatrix::new( 1, 2, vec![ 1.5, 2.0 ] ).unwrap();
+///
+/// assert_eq!( ( a * 2.0 ).as_slice(), &[ 3.0, 4.0 ] );
+/// ```
+///
+impl<T: Scalar> Mul<T> for DMatrix<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|a| a * rhs)
+    }
+}
+
+/// Implements Neg for DMatrix
+impl<T: Scalar + Neg<Output = T>> Neg for DMatrix<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
+
+impl<T: Scalar> DMatrix<T> {
+    /// Generates a new instance of DMatrix from its shape and row major elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 2, vec![ 1.0, 2.0, 3.0, 4.0 ] ).unwrap();
+    ///
+    /// assert_eq!( a[ ( 1, 0 ) ], 3.0 );
+    /// assert!( DMatrix::new( 2, 2, vec![ 1.0 ] ).is_err() );
+    /// assert!( DMatrix::<f64>::new( usize::MAX, 2, vec![] ).is_err() );
+    /// ```
+    ///
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Result<DMatrix<T>, MatrixError> {
+        if rows.checked_mul(cols) != Some(data.len()) {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (rows, cols),
+                found: (data.len(), 1),
+            });
+        }
+        Ok(DMatrix { rows, cols, data })
+    }
+
+    /// Generates a new instance of DMatrix from a slice of rows, which must all be the same length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::from_rows( &[ vec![ 1, 2 ], vec![ 3, 4 ] ] ).unwrap();
+    ///
+    /// assert_eq!( a.shape(), ( 2, 2 ) );
+    /// assert!( DMatrix::from_rows( &[ vec![ 1, 2 ], vec![ 3 ] ] ).is_err() );
+    /// ```
+    ///
+    pub fn from_rows<R: AsRef<[T]>>(rows: &[R]) -> Result<DMatrix<T>, MatrixError> {
+        let cols = rows.first().map_or(0, |row| row.as_ref().len());
+        let mut data = Vec::with_capacity(rows.len() * cols);
+
+        for row in rows {
+            let row = row.as_ref();
+            if row.len() != cols {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (1, cols),
+                    found: (1, row.len()),
+                });
+            }
+            data.extend_from_slice(row);
+        }
+
+        Ok(DMatrix {
+            rows: rows.len(),
+            cols,
+            data,
+        })
+    }
+
+    /// Generates a new instance of DMatrix where each element is the result of f( row, column ), panicking if
+    /// rows * cols overflows usize
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, cols: usize, mut f: F) -> DMatrix<T> {
+        let len = rows
+            .checked_mul(cols)
+            .expect("matrix shape overflows usize");
+        let mut data = Vec::with_capacity(len);
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(i, j));
+            }
+        }
+        DMatrix { rows, cols, data }
+    }
+
+    /// Generates a new column vector shaped DMatrix from a slice
+    pub fn from_column(col: &[T]) -> DMatrix<T> {
+        DMatrix {
+            rows: col.len(),
+            cols: 1,
+            data: col.to_vec(),
+        }
+    }
+
+    /// Generates a new instance of DMatrix initialized to zero and returns it, panicking if rows * cols overflows
+    /// usize
+    pub fn zeros(rows: usize, cols: usize) -> DMatrix<T> {
+        let len = rows
+            .checked_mul(cols)
+            .expect("matrix shape overflows usize");
+        DMatrix {
+            rows,
+            cols,
+            data: vec![T::ZERO; len],
+        }
+    }
+
+    /// Generates a new n x n identity DMatrix and returns it
+    pub fn identity(n: usize) -> DMatrix<T> {
+        DMatrix::from_fn(n, n, |i, j| if i == j { T::ONE } else { T::ZERO })
+    }
+
+    /// Returns the number of rows in self
+    pub fn nrows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in self
+    pub fn ncols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the shape of self as ( rows, columns )
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns true if self has as many rows as columns
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Returns a reference to the element at ( row, column ), or None if it is out of bounds
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.data.get(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the elements of self in row major order
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Consumes self and returns its elements in row major order
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Returns the row at index i
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns a copy of the column at index j
+    pub fn col(&self, j: usize) -> Vec<T> {
+        (0..self.rows).map(|i| self[(i, j)]).collect()
+    }
+
+    /// Returns an iterator over the rows of self
+    pub fn row_iter(&self) -> impl Iterator<Item = &[T]> {
+        /* chunks_exact panics on a chunk size of 0, an empty row still needs to be yielded once per row */
+        (0..self.rows).map(move |i| self.row(i))
+    }
+
+    /// Returns an iterator over the columns of self
+    pub fn col_iter(&self) -> impl Iterator<Item = Vec<T>> + '_ {
+        (0..self.cols).map(move |j| self.col(j))
+    }
+
+    /// Generates a new instance of DMatrix by applying f to each element of self
+    pub fn map<U: Scalar, F: FnMut(T) -> U>(&self, f: F) -> DMatrix<U> {
+        DMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().copied().map(f).collect(),
+        }
+    }
+
+    /// Generates the transpose of self and returns it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 3, vec![ 1, 2, 3, 4, 5, 6 ] ).unwrap();
+    ///
+    /// assert_eq!( a.transpose().as_slice(), &[ 1, 4, 2, 5, 3, 6 ] );
+    /// ```
+    ///
+    pub fn transpose(&self) -> DMatrix<T> {
+        DMatrix::from_fn(self.cols, self.rows, |i, j| self[(j, i)])
+    }
+
+    /* Checks that rhs has the same shape as self */
+    fn check_same_shape(&self, rhs: &DMatrix<T>) -> Result<(), MatrixError> {
+        if self.shape() != rhs.shape() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.shape(),
+                found: rhs.shape(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Generates a new instance of DMatrix by applying f to each pair of corresponding elements of self and rhs
+    pub fn zip_map<F: FnMut(T, T) -> T>(
+        &self,
+        rhs: &DMatrix<T>,
+        mut f: F,
+    ) -> Result<DMatrix<T>, MatrixError> {
+        self.check_same_shape(rhs)?;
+        Ok(DMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(rhs.data.iter())
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+        })
+    }
+
+    /// Adds each element of rhs to the corresponding element of self, the shapes must match
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 1, 2, vec![ 1, 2 ] ).unwrap();
+    /// let b = DMatrix::new( 2, 1, vec![ 1, 2 ] ).unwrap();
+    ///
+    /// assert_eq!( a.try_add( &a ).unwrap().as_slice(), &[ 2, 4 ] );
+    /// assert_eq!(
+    ///     a.try_add( &b ),
+    ///     Err( MatrixError::DimensionMismatch { expected: ( 1, 2 ), found: ( 2, 1 ) } )
+    /// );
+    /// ```
+    ///
+    pub fn try_add(&self, rhs: &DMatrix<T>) -> Result<DMatrix<T>, MatrixError> {
+        self.zip_map(rhs, |a, b| a + b)
+    }
+
+    /// Subtracts each element of rhs from the corresponding element of self, the shapes must match
+    pub fn try_sub(&self, rhs: &DMatrix<T>) -> Result<DMatrix<T>, MatrixError> {
+        self.zip_map(rhs, |a, b| a - b)
+    }
+
+    /// Multiplies each element of self by the corresponding element of rhs, the shapes must match
+    pub fn component_mul(&self, rhs: &DMatrix<T>) -> Result<DMatrix<T>, MatrixError> {
+        self.zip_map(rhs, |a, b| a * b)
+    }
+
+    /// Calculates the matrix product of self and rhs, self must have as many columns as rhs has rows
+    pub fn try_mul(&self, rhs: &DMatrix<T>) -> Result<DMatrix<T>, MatrixError> {
+        if self.cols != rhs.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.cols, rhs.cols),
+                found: rhs.shape(),
+            });
+        }
+
+        let mut out = DMatrix::zeros(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                for j in 0..rhs.cols {
+                    out.data[i * rhs.cols + j] += a * rhs[(k, j)];
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Multiplies self by the column vector rhs, rhs must have as many elements as self has columns
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 2, vec![ 1, 2, 3, 4 ] ).unwrap();
+    ///
+    /// assert_eq!( a.mul_vec( &[ 1, 1 ] ).unwrap(), vec![ 3, 7 ] );
+    /// ```
+    ///
+    pub fn mul_vec(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+        if self.cols != rhs.len() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.cols, 1),
+                found: (rhs.len(), 1),
+            });
+        }
+        Ok(self
+            .row_iter()
+            .map(|row| {
+                row.iter()
+                    .zip(rhs.iter())
+                    .fold(T::ZERO, |acc, (a, b)| acc + *a * *b)
+            })
+            .collect())
+    }
+
+    /// Returns a borrowed view of the sub-matrix covering the ranges of rows and columns entered
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 3, 3, vec![ 1, 2, 3, 4, 5, 6, 7, 8, 9 ] ).unwrap();
+    /// let view = a.slice( 1..3, 0..2 ).unwrap();
+    ///
+    /// assert_eq!( view[ ( 0, 1 ) ], 5 );
+    /// assert_eq!( view.to_owned().as_slice(), &[ 4, 5, 7, 8 ] );
+    /// assert!( a.slice( 0..4, 0..1 ).is_err() );
+    /// ```
+    ///
+    pub fn slice(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<DMatrixView<'_, T>, MatrixError> {
+        if rows.start > rows.end
+            || rows.end > self.rows
+            || cols.start > cols.end
+            || cols.end > self.cols
+        {
+            return Err(MatrixError::OutOfBounds {
+                index: (rows.end, cols.end),
+                shape: self.shape(),
+            });
+        }
+        Ok(DMatrixView {
+            parent: self,
+            rows,
+            cols,
+        })
+    }
+
+    /// Reinterprets the elements of self as a matrix of a different shape with the same number of elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 3, vec![ 1, 2, 3, 4, 5, 6 ] ).unwrap();
+    /// let b = a.reshape( 3, 2 ).unwrap();
+    ///
+    /// assert_eq!( b.row( 2 ), &[ 5, 6 ] );
+    /// assert!( b.reshape( 4, 2 ).is_err() );
+    /// assert!( b.reshape( usize::MAX, 2 ).is_err() );
+    /// ```
+    ///
+    pub fn reshape(&self, rows: usize, cols: usize) -> Result<DMatrix<T>, MatrixError> {
+        if rows.checked_mul(cols) != Some(self.data.len()) {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.shape(),
+                found: (rows, cols),
+            });
+        }
+        Ok(DMatrix {
+            rows,
+            cols,
+            data: self.data.clone(),
+        })
+    }
+
+    /// Joins matrices side by side, they must all have the same number of rows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 1, vec![ 1, 3 ] ).unwrap();
+    /// let b = DMatrix::new( 2, 1, vec![ 2, 4 ] ).unwrap();
+    ///
+    /// assert_eq!( DMatrix::hstack( &[ &a, &b ] ).unwrap().as_slice(), &[ 1, 2, 3, 4 ] );
+    /// ```
+    ///
+    pub fn hstack(matrices: &[&DMatrix<T>]) -> Result<DMatrix<T>, MatrixError> {
+        let rows = matrices.first().map_or(0, |m| m.rows);
+        if let Some(m) = matrices.iter().find(|m| m.rows != rows) {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (rows, m.cols),
+                found: m.shape(),
+            });
+        }
+
+        let mut cols = 0usize;
+        for m in matrices {
+            cols = cols
+                .checked_add(m.cols)
+                .filter(|&cols| rows.checked_mul(cols).is_some())
+                .ok_or(MatrixError::DimensionMismatch {
+                    expected: (rows, cols),
+                    found: m.shape(),
+                })?;
+        }
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for m in matrices {
+                data.extend_from_slice(m.row(i));
+            }
+        }
+        Ok(DMatrix { rows, cols, data })
+    }
+
+    /// Joins matrices one above the other, they must all have the same number of columns
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 1, 2, vec![ 1, 2 ] ).unwrap();
+    /// let b = DMatrix::new( 1, 2, vec![ 3, 4 ] ).unwrap();
+    /// let c = DMatrix::new( 1, 3, vec![ 3, 4, 5 ] ).unwrap();
+    ///
+    /// assert_eq!( DMatrix::vstack( &[ &a, &b ] ).unwrap().shape(), ( 2, 2 ) );
+    /// assert!( DMatrix::vstack( &[ &a, &c ] ).is_err() );
+    /// ```
+    ///
+    pub fn vstack(matrices: &[&DMatrix<T>]) -> Result<DMatrix<T>, MatrixError> {
+        let cols = matrices.first().map_or(0, |m| m.cols);
+        if let Some(m) = matrices.iter().find(|m| m.cols != cols) {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (m.rows, cols),
+                found: m.shape(),
+            });
+        }
+
+        let mut rows = 0usize;
+        for m in matrices {
+            rows = rows
+                .checked_add(m.rows)
+                .filter(|&rows| rows.checked_mul(cols).is_some())
+                .ok_or(MatrixError::DimensionMismatch {
+                    expected: (rows, cols),
+                    found: m.shape(),
+                })?;
+        }
+        let mut data = Vec::with_capacity(rows * cols);
+        for m in matrices {
+            data.extend_from_slice(&m.data);
+        }
+        Ok(DMatrix { rows, cols, data })
+    }
+}
+
+impl<T: Float> DMatrix<T> {
+    /// Returns true if self is square and equal to its transpose, to within a tolerance scaled by its largest entry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 2, vec![ 1.0, 2.0, 2.0, 1.0 ] ).unwrap();
+    /// let b = DMatrix::new( 2, 2, vec![ 1.0, 2.0, 3.0, 1.0 ] ).unwrap();
+    ///
+    /// assert!( a.is_symmetric() );
+    /// assert!( !b.is_symmetric() );
+    /// ```
+    ///
+    pub fn is_symmetric(&self) -> bool {
+        self.check_symmetric().is_ok()
+    }
+
+    /* Fails with NotSquare or NotSymmetric, used by the decompositions which need a symmetric matrix */
+    pub(super) fn check_symmetric(&self) -> Result<(), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+
+        let max_abs = self.data.iter().fold(T::ZERO, |acc, x| acc.max(x.abs()));
+        let tolerance = T::from_f64(1e3) * T::EPSILON * max_abs;
+        for i in 0..self.rows {
+            for j in (i + 1)..self.cols {
+                if (self[(i, j)] - self[(j, i)]).abs() > tolerance {
+                    return Err(MatrixError::NotSymmetric);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A borrowed rectangular region of a DMatrix
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a: DMatrix<f64> = DMatrix::identity( 4 );
+/// let view = a.slice( 1..3, 1..4 ).unwrap();
+///
+/// assert_eq!( view.shape(), ( 2, 3 ) );
+/// assert_eq!( view.get( 0, 0 ), Some( &1.0 ) );
+/// assert_eq!( view.get( 2, 0 ), None );
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct DMatrixView<'a, T> {
+    /// The matrix being viewed
+    parent: &'a DMatrix<T>,
+    /// The rows of the parent covered by the view
+    rows: Range<usize>,
+    /// The columns of the parent covered by the view
+    cols: Range<usize>,
+}
+
+/// Implements Index for DMatrixView, indexed by ( row, column ) relative to the view
+impl<T> Index<(usize, usize)> for DMatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        assert!(
+            index.0 < self.rows.len() && index.1 < self.cols.len(),
+            "index out of bounds of the view"
+        );
+        &self.parent[(self.rows.start + index.0, self.cols.start + index.1)]
+    }
+}
+
+impl<T: Scalar> DMatrixView<'_, T> {
+    /// Returns the number of rows in self
+    pub fn nrows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the number of columns in self
+    pub fn ncols(&self) -> usize {
+        self.cols.len()
+    }
+
+    /// Returns the shape of self as ( rows, columns )
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows(), self.ncols())
+    }
+
+    /// Returns a reference to the element at ( row, column ) of the view, or None if it is out of bounds
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.nrows() && col < self.ncols() {
+            Some(&self[(row, col)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the part of a row of the parent matrix covered by the view
+    pub fn row(&self, i: usize) -> &[T] {
+        assert!(i < self.nrows(), "row index out of bounds of the view");
+        &self.parent.row(self.rows.start + i)[self.cols.clone()]
+    }
+
+    /// Returns an iterator over the rows of the view
+    pub fn row_iter(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.nrows()).map(move |i| self.row(i))
+    }
+
+    /// Copies the viewed elements into a new DMatrix
+    pub fn to_owned(&self) -> DMatrix<T> {
+        DMatrix::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)])
+    }
+}
+
+/// Implements From<Matrix> for DMatrix
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = Matrix::from_rows( [ [ 1, 2 ], [ 3, 4 ] ] );
+/// let b = DMatrix::from( a );
+///
+/// assert_eq!( Matrix::<i32, 2, 2>::try_from( b.clone() ), Ok( a ) );
+/// assert!( Matrix::<i32, 3, 2>::try_from( b ).is_err() );
+/// ```
+///
+impl<T: Scalar, const R: usize, const C: usize> From<Matrix<T, R, C>> for DMatrix<T> {
+    fn from(mat: Matrix<T, R, C>) -> Self {
+        DMatrix {
+            rows: R,
+            cols: C,
+            data: mat.iter().copied().collect(),
+        }
+    }
+}
+
+/// Implements TryFrom<DMatrix> for Matrix, failing when the shapes differ
+impl<T: Scalar, const R: usize, const C: usize> TryFrom<DMatrix<T>> for Matrix<T, R, C> {
+    type Error = MatrixError;
+
+    fn try_from(mat: DMatrix<T>) -> Result<Self, Self::Error> {
+        if mat.shape() != (R, C) {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (R, C),
+                found: mat.shape(),
+            });
+        }
+        Ok(Matrix::from_fn(|i, j| mat[(i, j)]))
+    }
+}
+
+/* Conversions between the fixed size square matrices and DMatrix */
+macro_rules! impl_dmatrix_conversions {
+    ($mat:ident, $n:literal) => {
+        /// Implements From for DMatrix
+        impl From<$mat> for DMatrix<f32> {
+            fn from(mat: $mat) -> Self {
+                DMatrix::from(Matrix::from(mat))
+            }
+        }
+
+        /// Implements TryFrom<DMatrix>, failing when the shapes differ
+        impl TryFrom<DMatrix<f32>> for $mat {
+            type Error = MatrixError;
+
+            fn try_from(mat: DMatrix<f32>) -> Result<Self, Self::Error> {
+                Matrix::<f32, $n, $n>::try_from(mat).map($mat::from)
+            }
+        }
+    };
+}
+
+impl_dmatrix_conversions!(Mat2, 2);
+impl_dmatrix_conversions!(Mat3, 3);
+impl_dmatrix_conversions!(Mat4, 4);
@@ -0,0 +1,389 @@
+use super::{DMatrix, MatrixError};
+use crate::linear::complex::Complex;
+use crate::linear::scalar::Float;
+
+/* The number of sweeps / iterations after which the solvers give up */
+const MAX_JACOBI_SWEEPS: usize = 100;
+const MAX_QR_ITERATIONS: usize = 60;
+
+/// The eigendecomposition of a real symmetric matrix, such that A = V * diag( eigenvalues ) * V^T
+///
+/// The eigenvalues are sorted in ascending order and column i of V is the unit eigenvector of eigenvalue i
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = DMatrix::new( 2, 2, vec![ 2.0, 1.0, 1.0, 2.0 ] ).unwrap();
+/// let eigen = a.symmetric_eigen().unwrap();
+///
+/// assert!( ( eigen.eigenvalues[ 0 ] - 1.0_f64 ).abs() < 1e-12 );
+/// assert!( ( eigen.eigenvalues[ 1 ] - 3.0_f64 ).abs() < 1e-12 );
+///
+/// // A * v == lambda * v
+/// let v = eigen.eigenvector( 1 );
+/// let av = a.try_mul( &v ).unwrap();
+/// for ( found, expected ) in av.as_slice().iter().zip( ( v * 3.0 ).as_slice() ) {
+///     assert!( ( found - expected ).abs() < 1e-12 );
+/// }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct SymmetricEigen<T> {
+    /// The eigenvalues, in ascending order
+    pub eigenvalues: Vec<T>,
+    /// The orthonormal eigenvectors, stored as the columns of the matrix
+    pub eigenvectors: DMatrix<T>,
+}
+
+impl<T: Float> SymmetricEigen<T> {
+    /// Returns the unit eigenvector of the eigenvalue at index i as a column matrix
+    pub fn eigenvector(&self, i: usize) -> DMatrix<T> {
+        DMatrix::from_column(&self.eigenvectors.col(i))
+    }
+}
+
+impl<T: Float> DMatrix<T> {
+    /// Calculates the eigenvalues and eigenvectors of a symmetric matrix with the cyclic Jacobi method
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 3, 3, vec![ 4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0 ] ).unwrap();
+    /// let eigen = a.symmetric_eigen().unwrap();
+    ///
+    /// // The eigenvectors are orthonormal, so V^T * V == I
+    /// let vtv = eigen.eigenvectors.transpose().try_mul( &eigen.eigenvectors ).unwrap();
+    /// for ( found, expected ) in vtv.as_slice().iter().zip( DMatrix::<f64>::identity( 3 ).as_slice() ) {
+    ///     assert!( ( found - expected ).abs() < 1e-12 );
+    /// }
+    ///
+    /// // The trace is the sum of the eigenvalues
+    /// assert!( ( eigen.eigenvalues.iter().sum::<f64>() - 9.0 ).abs() < 1e-12 );
+    ///
+    /// let b = DMatrix::new( 2, 2, vec![ 1.0, 2.0, 3.0, 4.0 ] ).unwrap();
+    /// assert_eq!( b.symmetric_eigen().unwrap_err(), MatrixError::NotSymmetric );
+    /// ```
+    ///
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T>, MatrixError> {
+        self.check_symmetric()?;
+
+        let n = self.nrows();
+        let mut a = self.clone();
+        let mut v = DMatrix::identity(n);
+        let total = a.as_slice().iter().fold(T::ZERO, |acc, x| acc + *x * *x);
+
+        let mut converged = false;
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let off = (0..n)
+                .flat_map(|i| (0..n).filter(move |j| *j != i).map(move |j| (i, j)))
+                .fold(T::ZERO, |acc, (i, j)| acc + a[(i, j)] * a[(i, j)]);
+            if off <= T::EPSILON * T::EPSILON * total {
+                converged = true;
+                break;
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    if a[(p, q)] == T::ZERO {
+                        continue;
+                    }
+                    jacobi_rotate(&mut a, &mut v, p, q);
+                }
+            }
+        }
+        if !converged {
+            return Err(MatrixError::NoConvergence);
+        }
+
+        /* Sort the eigenpairs by ascending eigenvalue */
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| {
+            a[(i, i)]
+                .partial_cmp(&a[(j, j)])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        Ok(SymmetricEigen {
+            eigenvalues: order.iter().map(|&i| a[(i, i)]).collect(),
+            eigenvectors: DMatrix::from_fn(n, n, |i, j| v[(i, order[j])]),
+        })
+    }
+
+    /// Calculates the eigenvalues of a general square matrix, which may be complex
+    ///
+    /// The matrix is reduced to upper Hessenberg form with Householder reflections and then to quasi-triangular form
+    /// with the Francis double shift QR algorithm.
+    /// The eigenvalues are sorted by ascending real part, then by ascending imaginary part
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// // A rotation by 90 degrees has the eigenvalues +i and -i
+    /// let a = DMatrix::new( 2, 2, vec![ 0.0, -1.0, 1.0, 0.0 ] ).unwrap();
+    /// let values = a.eigenvalues().unwrap();
+    ///
+    /// assert!( values[ 0 ].re.abs() < 1e-12 && ( values[ 0 ].im + 1.0_f64 ).abs() < 1e-12 );
+    /// assert!( values[ 1 ].re.abs() < 1e-12 && ( values[ 1 ].im - 1.0_f64 ).abs() < 1e-12 );
+    ///
+    /// let b = DMatrix::new( 3, 3, vec![ 2.0, 0.0, 0.0, 1.0, 3.0, 0.0, 4.0, 5.0, 6.0 ] ).unwrap();
+    /// let values = b.eigenvalues().unwrap();
+    ///
+    /// for ( found, expected ) in values.iter().zip( [ 2.0, 3.0, 6.0 ] ) {
+    ///     assert!( ( found.re - expected ).abs() < 1e-12 && found.is_real( 1e-12 ) );
+    /// }
+    ///
+    /// // A cyclic permutation stalls the plain shifted iteration, its eigenvalues are the fourth roots of unity
+    /// let c = DMatrix::new( 4, 4, vec![
+    ///     0.0, 0.0, 0.0, 1.0,
+    ///     1.0, 0.0, 0.0, 0.0,
+    ///     0.0, 1.0, 0.0, 0.0,
+    ///     0.0, 0.0, 1.0, 0.0,
+    /// ] ).unwrap();
+    /// let values = c.eigenvalues().unwrap();
+    ///
+    /// for ( found, expected ) in values.iter().zip( [ ( -1.0, 0.0 ), ( 0.0, -1.0 ), ( 0.0, 1.0 ), ( 1.0, 0.0 ) ] ) {
+    ///     assert!( ( found.re - expected.0 ).abs() < 1e-12 && ( found.im - expected.1 ).abs() < 1e-12 );
+    /// }
+    /// ```
+    ///
+    pub fn eigenvalues(&self) -> Result<Vec<Complex<T>>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+
+        let mut h = self.clone();
+        reduce_to_hessenberg(&mut h);
+        let mut values = hessenberg_qr(&mut h)?;
+
+        values.sort_by(|x, y| {
+            x.re.partial_cmp(&y.re)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(x.im.partial_cmp(&y.im).unwrap_or(std::cmp::Ordering::Equal))
+        });
+        Ok(values)
+    }
+}
+
+/* Applies the Jacobi rotation which zeroes a[ p ][ q ], accumulating it into the eigenvectors v */
+fn jacobi_rotate<T: Float>(a: &mut DMatrix<T>, v: &mut DMatrix<T>, p: usize, q: usize) {
+    let n = a.nrows();
+    let two = T::ONE + T::ONE;
+
+    let theta = (a[(q, q)] - a[(p, p)]) / (two * a[(p, q)]);
+    /* The smaller root of t^2 + 2 * t * theta - 1 = 0 keeps the rotation angle below pi / 4 */
+    let t = if theta >= T::ZERO { T::ONE } else { -T::ONE } / (theta.abs() + theta.hypot(T::ONE));
+    let c = T::ONE / t.hypot(T::ONE);
+    let s = t * c;
+
+    for k in 0..n {
+        let (akp, akq) = (a[(k, p)], a[(k, q)]);
+        a[(k, p)] = c * akp - s * akq;
+        a[(k, q)] = s * akp + c * akq;
+    }
+    for k in 0..n {
+        let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+        a[(p, k)] = c * apk - s * aqk;
+        a[(q, k)] = s * apk + c * aqk;
+    }
+    for k in 0..n {
+        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+        v[(k, p)] = c * vkp - s * vkq;
+        v[(k, q)] = s * vkp + c * vkq;
+    }
+}
+
+/* Builds the Householder reflector I - beta * u * u^T which maps x onto a multiple of the first
+unit vector, returns None if x is already zero */
+fn reflector<T: Float>(x: &[T]) -> Option<(Vec<T>, T)> {
+    let norm = x.iter().fold(T::ZERO, |acc, v| acc.hypot(*v));
+    if norm == T::ZERO {
+        return None;
+    }
+
+    /* Choosing the sign opposite to the leading element avoids cancellation */
+    let mut u = x.to_vec();
+    u[0] += if x[0] > T::ZERO { norm } else { -norm };
+    let u_sq = u.iter().fold(T::ZERO, |acc, v| acc + *v * *v);
+    Some((u, (T::ONE + T::ONE) / u_sq))
+}
+
+/* Applies the reflector from the left to the rows starting at row, over the columns in cols */
+fn reflect_rows<T: Float>(
+    h: &mut DMatrix<T>,
+    (u, beta): &(Vec<T>, T),
+    row: usize,
+    cols: std::ops::Range<usize>,
+) {
+    for j in cols {
+        let dot = u
+            .iter()
+            .enumerate()
+            .fold(T::ZERO, |acc, (i, ui)| acc + *ui * h[(row + i, j)]);
+        for (i, ui) in u.iter().enumerate() {
+            h[(row + i, j)] -= *beta * *ui * dot;
+        }
+    }
+}
+
+/* Applies the reflector from the right to the columns starting at col, over the rows in rows */
+fn reflect_cols<T: Float>(
+    h: &mut DMatrix<T>,
+    (u, beta): &(Vec<T>, T),
+    col: usize,
+    rows: std::ops::Range<usize>,
+) {
+    for i in rows {
+        let dot = u
+            .iter()
+            .enumerate()
+            .fold(T::ZERO, |acc, (j, uj)| acc + *uj * h[(i, col + j)]);
+        for (j, uj) in u.iter().enumerate() {
+            h[(i, col + j)] -= *beta * *uj * dot;
+        }
+    }
+}
+
+/* Reduces the square matrix h to upper Hessenberg form with a similarity transform made of Householder reflections */
+fn reduce_to_hessenberg<T: Float>(h: &mut DMatrix<T>) {
+    let n = h.nrows();
+    for k in 0..n.saturating_sub(2) {
+        let x: Vec<T> = ((k + 1)..n).map(|i| h[(i, k)]).collect();
+        if let Some(reflector) = reflector(&x) {
+            reflect_rows(h, &reflector, k + 1, k..n);
+            reflect_cols(h, &reflector, k + 1, 0..n);
+        }
+
+        /* Clean up the rounding left below the subdiagonal */
+        for i in (k + 2)..n {
+            h[(i, k)] = T::ZERO;
+        }
+    }
+}
+
+/* The eigenvalues of the 2 x 2 block of h with its top left corner at ( k, k ) */
+fn block_eigenvalues<T: Float>(h: &DMatrix<T>, k: usize) -> [Complex<T>; 2] {
+    let (a, b, c, d) = (h[(k, k)], h[(k, k + 1)], h[(k + 1, k)], h[(k + 1, k + 1)]);
+    let half = T::from_f64(0.5);
+    let mean = half * (a + d);
+    let diff = half * (a - d);
+    let disc = diff * diff + b * c;
+
+    if disc >= T::ZERO {
+        /* Take the root of larger magnitude directly and the other from the determinant, avoiding cancellation */
+        let root = diff
+            + if diff >= T::ZERO {
+                disc.sqrt()
+            } else {
+                -disc.sqrt()
+            };
+        let first = d + root;
+        let second = if root == T::ZERO { d } else { d - b * c / root };
+        [Complex::new(first, T::ZERO), Complex::new(second, T::ZERO)]
+    } else {
+        let im = (-disc).sqrt();
+        [Complex::new(mean, im), Complex::new(mean, -im)]
+    }
+}
+
+/* Finds every eigenvalue of the upper Hessenberg matrix h with the Francis double shift QR algorithm,
+following Golub and Van Loan. The active block h[ lo..=hi ] is iterated on until a subdiagonal element
+becomes negligible, then the one or two eigenvalues split off below it are read and the block shrinks */
+fn hessenberg_qr<T: Float>(h: &mut DMatrix<T>) -> Result<Vec<Complex<T>>, MatrixError> {
+    let n = h.nrows();
+    let mut values = Vec::with_capacity(n);
+    let norm = h.as_slice().iter().fold(T::ZERO, |acc, x| acc + x.abs());
+
+    let mut hi = n;
+    let mut iterations = 0;
+    while hi > 0 {
+        let last = hi - 1;
+
+        /* Find the start of the unreduced block ending at last, zeroing the negligible subdiagonal element above it */
+        let mut lo = last;
+        while lo > 0 {
+            let mut scale = h[(lo - 1, lo - 1)].abs() + h[(lo, lo)].abs();
+            if scale == T::ZERO {
+                scale = norm;
+            }
+            if h[(lo, lo - 1)].abs() <= T::EPSILON * scale {
+                h[(lo, lo - 1)] = T::ZERO;
+                break;
+            }
+            lo -= 1;
+        }
+
+        if lo == last {
+            values.push(Complex::new(h[(last, last)], T::ZERO));
+            hi -= 1;
+            iterations = 0;
+            continue;
+        }
+        if lo + 1 == last {
+            values.extend(block_eigenvalues(h, lo));
+            hi -= 2;
+            iterations = 0;
+            continue;
+        }
+
+        if iterations == MAX_QR_ITERATIONS {
+            return Err(MatrixError::NoConvergence);
+        }
+        iterations += 1;
+
+        /* The shifts are the eigenvalues of the trailing 2 x 2 block, entering only through its trace and
+        determinant. Every tenth step uses a pair of equal shifts made up from the size of the last
+        subdiagonal elements instead, breaking the cycles the usual shifts can fall into */
+        let (trace, det) = if iterations % 10 == 0 {
+            let shift = h[(last, last)] + h[(last, last - 1)].abs() + h[(last - 1, last - 2)].abs();
+            (shift + shift, shift * shift)
+        } else {
+            (
+                h[(last - 1, last - 1)] + h[(last, last)],
+                h[(last - 1, last - 1)] * h[(last, last)]
+                    - h[(last - 1, last)] * h[(last, last - 1)],
+            )
+        };
+
+        /* The first column of ( H - s1 I )( H - s2 I ), which only has three nonzero elements */
+        let mut x = h[(lo, lo)] * h[(lo, lo)] + h[(lo, lo + 1)] * h[(lo + 1, lo)]
+            - trace * h[(lo, lo)]
+            + det;
+        let mut y = h[(lo + 1, lo)] * (h[(lo, lo)] + h[(lo + 1, lo + 1)] - trace);
+        let mut z = h[(lo + 1, lo)] * h[(lo + 2, lo + 1)];
+
+        /* Introduce the bulge at the top of the block and chase it down and off the bottom */
+        for k in lo..(last - 1) {
+            if let Some(reflector) = reflector(&[x, y, z]) {
+                let first = if k > lo { k - 1 } else { lo };
+                reflect_rows(h, &reflector, k, first..hi);
+                reflect_cols(h, &reflector, k, lo..hi.min(k + 4));
+            }
+
+            x = h[(k + 1, k)];
+            y = h[(k + 2, k)];
+            if k + 3 < hi {
+                z = h[(k + 3, k)];
+            }
+            if k > lo {
+                h[(k + 1, k - 1)] = T::ZERO;
+                h[(k + 2, k - 1)] = T::ZERO;
+            }
+        }
+
+        if let Some(reflector) = reflector(&[x, y]) {
+            reflect_rows(h, &reflector, last - 1, (last - 2)..hi);
+            reflect_cols(h, &reflector, last - 1, lo..hi);
+        }
+        h[(last, last - 2)] = T::ZERO;
+    }
+
+    Ok(values)
+}
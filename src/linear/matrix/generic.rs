@@ -0,0 +1,511 @@
+use super::{Mat2, Mat3, Mat4};
+use crate::linear::scalar::Scalar;
+use crate::linear::vector::{Vec2, Vec3, Vec4};
+
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+/// A dense R x C matrix with its shape checked at compile time, stored as an array of rows
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = Matrix::from_rows( [ [ 1, 2, 3 ], [ 4, 5, 6 ] ] );
+/// let b = Matrix::from_rows( [ [ 1, 0 ], [ 0, 1 ], [ 1, 1 ] ] );
+///
+/// // A 2x3 matrix times a 3x2 matrix is a 2x2 matrix
+/// let c: Matrix<i32, 2, 2> = a * b;
+///
+/// assert_eq!( c, Matrix::from_rows( [ [ 4, 5 ], [ 10, 11 ] ] ) );
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+    /// The elements of the matrix, indexed as data[ row ][ column ]
+    data: [[T; C]; R],
+}
+
+/// Implements Default for Matrix
+/// Generates a new instance of Matrix initialized to zero and returns it
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a: Matrix<f32, 3, 2> = Matrix::default();
+///
+/// assert!( a.iter().all( | x | *x == 0.0 ) );
+/// ```
+///
+impl<T: Scalar, const R: usize, const C: usize> Default for Matrix<T, R, C> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+/// Implements Add for Matrix, adding each element of rhs to the corresponding element of self
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = Matrix::from_rows( [ [ 1, 2 ] ] );
+/// let b = Matrix::from_rows( [ [ 3, 4 ] ] );
+///
+/// assert_eq!( a + b, Matrix::from_rows( [ [ 4, 6 ] ] ) );
+/// ```
+///
+impl<T: Scalar, const R: usize, const C: usize> Add for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_map(&rhs, |a, b| a + b)
+    }
+}
+
+/// Implements AddAssign for Matrix
+impl<T: Scalar, const R: usize, const C: usize> AddAssign for Matrix<T, R, C> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Implements Sub for Matrix, subtracting each element of rhs from the corresponding element of self
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = Matrix::from_rows( [ [ 1, 2 ] ] );
+/// let b = Matrix::from_rows( [ [ 3, 4 ] ] );
+///
+/// assert_eq!( b - a, Matrix::from_rows( [ [ 2, 2 ] ] ) );
+/// ```
+///
+impl<T: Scalar, const R: usize, const C: usize> Sub for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_map(&rhs, |a, b| a - b)
+    }
+}
+
+/// Implements SubAssign for Matrix
+impl<T: Scalar, const R: usize, const C: usize> SubAssign for Matrix<T, R, C> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Implements Mul for Matrix, the matrix product of a R x K matrix and a K x C matrix is a R x C matrix
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = Matrix::from_rows( [ [ 1.0, 2.0 ] ] );
+/// let b = Matrix::from_rows( [ [ 3.0 ], [ 4.0 ] ] );
+///
+/// assert_eq!( a * b, Matrix::from_rows( [ [ 11.0 ] ] ) );
+/// ```
+///
+impl<T: Scalar, const R: usize, const K: usize, const C: usize> Mul<Matrix<T, K, C>>
+    for Matrix<T, R, K>
+{
+    type Output = Matrix<T, R, C>;
+
+    fn mul(self, rhs: Matrix<T, K, C>) -> Self::Output {
+        Matrix::from_fn(|i, j| {
+            (0..K).fold(T::ZERO, |acc, k| acc + self.data[i][k] * rhs.data[k][j])
+        })
+    }
+}
+
+/// Implements MulAssign for square Matrix
+impl<T: Scalar, const N: usize> MulAssign for Matrix<T, N, N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Implements Mul by a scalar for Matrix
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = Matrix::from_rows( [ [ 1, 2 ], [ 3, 4 ] ] );
+///
+/// assert_eq!( a * 2, Matrix::from_rows( [ [ 2, 4 ], [ 6, 8 ] ] ) );
+/// ```
+///
+impl<T: Scalar, const R: usize, const C: usize> Mul<T> for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|a| a * rhs)
+    }
+}
+
+/// Implements MulAssign by a scalar for Matrix
+impl<T: Scalar, const R: usize, const C: usize> MulAssign<T> for Matrix<T, R, C> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+/// Implements Div by a scalar for Matrix
+impl<T: Scalar, const R: usize, const C: usize> Div<T> for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|a| a / rhs)
+    }
+}
+
+/// Implements DivAssign by a scalar for Matrix
+impl<T: Scalar, const R: usize, const C: usize> DivAssign<T> for Matrix<T, R, C> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+/// Implements Neg for Matrix
+impl<T: Scalar + Neg<Output = T>, const R: usize, const C: usize> Neg for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
+
+/// Implements Index for Matrix, indexed by ( row, column )
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.data[index.0][index.1]
+    }
+}
+
+/// Implements IndexMut for Matrix, indexed by ( row, column )
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        &mut self.data[index.0][index.1]
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Generates a new instance of Matrix from an array of rows and returns it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Matrix::from_rows( [ [ 1, 2, 3 ], [ 4, 5, 6 ] ] );
+    ///
+    /// assert_eq!( a[ ( 1, 2 ) ], 6 );
+    /// ```
+    ///
+    pub fn from_rows(rows: [[T; C]; R]) -> Matrix<T, R, C> {
+        Matrix { data: rows }
+    }
+
+    /// Generates a new instance of Matrix from an array of columns and returns it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Matrix::from_cols( [ [ 1, 2, 3 ], [ 4, 5, 6 ] ] );
+    ///
+    /// assert_eq!( a, Matrix::from_rows( [ [ 1, 4 ], [ 2, 5 ], [ 3, 6 ] ] ) );
+    /// ```
+    ///
+    pub fn from_cols(cols: [[T; R]; C]) -> Matrix<T, R, C> {
+        Matrix { data: cols }.transpose()
+    }
+
+    /// Generates a new instance of Matrix where each element is the result of f( row, column )
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a: Matrix<usize, 2, 2> = Matrix::from_fn( | i, j | i * 2 + j );
+    ///
+    /// assert_eq!( a, Matrix::from_rows( [ [ 0, 1 ], [ 2, 3 ] ] ) );
+    /// ```
+    ///
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut f: F) -> Matrix<T, R, C> {
+        let mut data = [[T::ZERO; C]; R];
+        for (i, row) in data.iter_mut().enumerate() {
+            for (j, elem) in row.iter_mut().enumerate() {
+                *elem = f(i, j);
+            }
+        }
+        Matrix { data }
+    }
+
+    /// Generates a new instance of Matrix initialized to zero and returns it
+    pub fn zero() -> Matrix<T, R, C> {
+        Matrix {
+            data: [[T::ZERO; C]; R],
+        }
+    }
+
+    /// Returns the number of rows in self
+    pub fn nrows(&self) -> usize {
+        R
+    }
+
+    /// Returns the number of columns in self
+    pub fn ncols(&self) -> usize {
+        C
+    }
+
+    /// Returns the underlying array of rows
+    pub fn to_rows(&self) -> [[T; C]; R] {
+        self.data
+    }
+
+    /// Returns a copy of the row at index i
+    pub fn row(&self, i: usize) -> [T; C] {
+        self.data[i]
+    }
+
+    /// Returns a copy of the column at index j
+    pub fn col(&self, j: usize) -> [T; R] {
+        let mut col = [T::ZERO; R];
+        for (i, elem) in col.iter_mut().enumerate() {
+            *elem = self.data[i][j];
+        }
+        col
+    }
+
+    /// Returns an iterator over the rows of self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Matrix::from_rows( [ [ 1, 2 ], [ 3, 4 ] ] );
+    /// let sums: Vec<i32> = a.row_iter().map( | row | row.iter().sum() ).collect();
+    ///
+    /// assert_eq!( sums, vec![ 3, 7 ] );
+    /// ```
+    ///
+    pub fn row_iter(&self) -> impl Iterator<Item = &[T; C]> {
+        self.data.iter()
+    }
+
+    /// Returns an iterator over the columns of self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Matrix::from_rows( [ [ 1, 2 ], [ 3, 4 ] ] );
+    /// let sums: Vec<i32> = a.col_iter().map( | col | col.iter().sum() ).collect();
+    ///
+    /// assert_eq!( sums, vec![ 4, 6 ] );
+    /// ```
+    ///
+    pub fn col_iter(&self) -> impl Iterator<Item = [T; R]> + '_ {
+        (0..C).map(move |j| self.col(j))
+    }
+
+    /// Returns an iterator over every element of self in row major order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter().flatten()
+    }
+
+    /// Generates a new instance of Matrix by applying f to each element of self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Matrix::from_rows( [ [ 1, -2 ], [ -3, 4 ] ] );
+    ///
+    /// assert_eq!( a.map( | x: i32 | x.abs() ), Matrix::from_rows( [ [ 1, 2 ], [ 3, 4 ] ] ) );
+    /// ```
+    ///
+    pub fn map<U: Scalar, F: FnMut(T) -> U>(&self, mut f: F) -> Matrix<U, R, C> {
+        Matrix::from_fn(|i, j| f(self.data[i][j]))
+    }
+
+    /// Generates a new instance of Matrix by applying f to each pair of corresponding elements of self and rhs
+    pub fn zip_map<F: FnMut(T, T) -> T>(&self, rhs: &Matrix<T, R, C>, mut f: F) -> Matrix<T, R, C> {
+        Matrix::from_fn(|i, j| f(self.data[i][j], rhs.data[i][j]))
+    }
+
+    /// Multiplies each element of self by the corresponding element of rhs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Matrix::from_rows( [ [ 1, 2 ], [ 3, 4 ] ] );
+    ///
+    /// assert_eq!( a.component_mul( &a ), Matrix::from_rows( [ [ 1, 4 ], [ 9, 16 ] ] ) );
+    /// ```
+    ///
+    pub fn component_mul(&self, rhs: &Matrix<T, R, C>) -> Matrix<T, R, C> {
+        self.zip_map(rhs, |a, b| a * b)
+    }
+
+    /// Divides each element of self by the corresponding element of rhs
+    pub fn component_div(&self, rhs: &Matrix<T, R, C>) -> Matrix<T, R, C> {
+        self.zip_map(rhs, |a, b| a / b)
+    }
+
+    /// Generates the transpose of self and returns it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Matrix::from_rows( [ [ 1, 2, 3 ] ] );
+    ///
+    /// assert_eq!( a.transpose(), Matrix::from_rows( [ [ 1 ], [ 2 ], [ 3 ] ] ) );
+    /// ```
+    ///
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        Matrix::from_fn(|i, j| self.data[j][i])
+    }
+}
+
+impl<T: Scalar, const N: usize> Matrix<T, N, N> {
+    /// Generates a new identity Matrix and returns it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Matrix::from_rows( [ [ 1, 2 ], [ 3, 4 ] ] );
+    ///
+    /// assert_eq!( a * Matrix::identity(), a );
+    /// ```
+    ///
+    pub fn identity() -> Matrix<T, N, N> {
+        Matrix::from_fn(|i, j| if i == j { T::ONE } else { T::ZERO })
+    }
+
+    /// Calculates the sum of the diagonal elements of self
+    pub fn trace(&self) -> T {
+        (0..N).fold(T::ZERO, |acc, i| acc + self.data[i][i])
+    }
+}
+
+/// Implements From<Vec2<T>> for a 2x1 Matrix, treating the Vec2 as a column vector
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a: Matrix<f32, 2, 1> = Vec2::new( &1.0, &2.0 ).into();
+/// let b: Matrix<i32, 2, 1> = Vec2i::new( &3, &4 ).into();
+///
+/// assert_eq!( Vec2::from( a ), Vec2::new( &1.0, &2.0 ) );
+/// assert_eq!( b[ ( 1, 0 ) ], 4 );
+/// ```
+///
+impl<T: Scalar> From<Vec2<T>> for Matrix<T, 2, 1> {
+    fn from(vec: Vec2<T>) -> Self {
+        Matrix::from_rows([[vec.x], [vec.y]])
+    }
+}
+
+/// Implements From<Matrix<T, 2, 1>> for Vec2<T>
+impl<T: Scalar> From<Matrix<T, 2, 1>> for Vec2<T> {
+    fn from(mat: Matrix<T, 2, 1>) -> Self {
+        Vec2::new(&mat.data[0][0], &mat.data[1][0])
+    }
+}
+
+/// Implements From<Vec3<T>> for a 3x1 Matrix, treating the Vec3 as a column vector
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a: Matrix<f32, 3, 3> = Matrix::identity() * 2.0;
+/// let b = a * Matrix::from( Vec3::new( &1.0, &2.0, &3.0 ) );
+///
+/// assert_eq!( Vec3::from( b ), Vec3::new( &2.0, &4.0, &6.0 ) );
+/// ```
+///
+impl<T: Scalar> From<Vec3<T>> for Matrix<T, 3, 1> {
+    fn from(vec: Vec3<T>) -> Self {
+        Matrix::from_rows([[vec.x], [vec.y], [vec.z]])
+    }
+}
+
+/// Implements From<Matrix<T, 3, 1>> for Vec3<T>
+impl<T: Scalar> From<Matrix<T, 3, 1>> for Vec3<T> {
+    fn from(mat: Matrix<T, 3, 1>) -> Self {
+        Vec3::new(&mat.data[0][0], &mat.data[1][0], &mat.data[2][0])
+    }
+}
+
+/// Implements From<Vec4<T>> for a 4x1 Matrix, treating the Vec4 as a column vector
+impl<T: Scalar> From<Vec4<T>> for Matrix<T, 4, 1> {
+    fn from(vec: Vec4<T>) -> Self {
+        Matrix::from_rows([[vec.x], [vec.y], [vec.z], [vec.w]])
+    }
+}
+
+/// Implements From<Matrix<T, 4, 1>> for Vec4<T>
+impl<T: Scalar> From<Matrix<T, 4, 1>> for Vec4<T> {
+    fn from(mat: Matrix<T, 4, 1>) -> Self {
+        Vec4::new(
+            &mat.data[0][0],
+            &mat.data[1][0],
+            &mat.data[2][0],
+            &mat.data[3][0],
+        )
+    }
+}
+
+/* Conversions between the fixed size square matrices and their generic equivalents */
+macro_rules! impl_mat_conversions {
+    ($mat:ident, $n:literal) => {
+        /// Implements From for the generic Matrix of the same size
+        impl From<$mat> for Matrix<f32, $n, $n> {
+            fn from(mat: $mat) -> Self {
+                Matrix::from_rows(mat.m)
+            }
+        }
+
+        /// Implements From for the fixed size matrix of the same size
+        impl From<Matrix<f32, $n, $n>> for $mat {
+            fn from(mat: Matrix<f32, $n, $n>) -> Self {
+                $mat::from_rows(&mat.data)
+            }
+        }
+    };
+}
+
+impl_mat_conversions!(Mat2, 2);
+impl_mat_conversions!(Mat3, 3);
+impl_mat_conversions!(Mat4, 4);
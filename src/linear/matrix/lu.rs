@@ -0,0 +1,273 @@
+use super::{DMatrix, MatrixError};
+use crate::linear::scalar::Float;
+
+/// The LU decomposition of a square matrix with partial pivoting, such that P * A = L * U
+///
+/// L is unit lower triangular and U is upper triangular, both are stored packed into a single matrix
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3
+/// let a = DMatrix::new( 3, 3, vec![ 2.0, 1.0, -1.0, -3.0, -1.0, 2.0, -2.0, 1.0, 2.0 ] ).unwrap();
+/// let lu = a.lu().unwrap();
+/// let x = lu.solve_vec( &[ 8.0, -11.0, -3.0 ] ).unwrap();
+///
+/// assert!( ( x[ 0 ] - 2.0_f64 ).abs() < 1e-12 );
+/// assert!( ( x[ 1 ] - 3.0_f64 ).abs() < 1e-12 );
+/// assert!( ( x[ 2 ] + 1.0_f64 ).abs() < 1e-12 );
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Lu<T> {
+    /// L below the diagonal ( its unit diagonal is implied ) and U on and above it
+    lu: DMatrix<T>,
+    /// Row i of P * A is row perm[ i ] of A
+    perm: Vec<usize>,
+    /// The number of row swaps performed, used for the sign of the determinant
+    swaps: usize,
+    /// Pivots with a magnitude at or below this are treated as zero
+    tolerance: T,
+}
+
+impl<T: Float> DMatrix<T> {
+    /// Calculates the LU decomposition of self with partial pivoting, self must be square
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 2, vec![ 1.0, 2.0, 3.0, 4.0 ] ).unwrap();
+    /// let lu = a.lu().unwrap();
+    ///
+    /// // P * A == L * U
+    /// assert_eq!( lu.permutation(), &[ 1, 0 ] );
+    /// assert_eq!( lu.l().try_mul( &lu.u() ).unwrap().as_slice(), &[ 3.0, 4.0, 1.0, 2.0 ] );
+    ///
+    /// let b = DMatrix::new( 2, 3, vec![ 0.0; 6 ] ).unwrap();
+    /// assert_eq!( b.lu().unwrap_err(), MatrixError::NotSquare { shape: ( 2, 3 ) } );
+    /// ```
+    ///
+    pub fn lu(&self) -> Result<Lu<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+
+        let n = self.nrows();
+        let mut lu = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+
+        /* Scale the singularity tolerance to the size of the entries, so the test doesn't depend on units */
+        let max_abs = self
+            .as_slice()
+            .iter()
+            .fold(T::ZERO, |acc, x| acc.max(x.abs()));
+        let tolerance = T::EPSILON * T::from_f64(n as f64) * max_abs;
+
+        for k in 0..n {
+            /* Partial pivoting: bring the row with the largest magnitude in column k up to row k */
+            let pivot_row = (k..n)
+                .max_by(|&a, &b| {
+                    lu[(a, k)]
+                        .abs()
+                        .partial_cmp(&lu[(b, k)].abs())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap_or(k);
+
+            if pivot_row != k {
+                for j in 0..n {
+                    let temp = lu[(k, j)];
+                    lu[(k, j)] = lu[(pivot_row, j)];
+                    lu[(pivot_row, j)] = temp;
+                }
+                perm.swap(k, pivot_row);
+                swaps += 1;
+            }
+
+            let pivot = lu[(k, k)];
+            if pivot.abs() <= tolerance {
+                /* The column is already zero below the diagonal, nothing to eliminate */
+                continue;
+            }
+
+            for i in (k + 1)..n {
+                let factor = lu[(i, k)] / pivot;
+                lu[(i, k)] = factor;
+                for j in (k + 1)..n {
+                    let u = lu[(k, j)];
+                    lu[(i, j)] -= factor * u;
+                }
+            }
+        }
+
+        Ok(Lu {
+            lu,
+            perm,
+            swaps,
+            tolerance,
+        })
+    }
+}
+
+impl<T: Float> Lu<T> {
+    /// Returns the unit lower triangular factor L
+    pub fn l(&self) -> DMatrix<T> {
+        let n = self.lu.nrows();
+        DMatrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+            std::cmp::Ordering::Greater => self.lu[(i, j)],
+            std::cmp::Ordering::Equal => T::ONE,
+            std::cmp::Ordering::Less => T::ZERO,
+        })
+    }
+
+    /// Returns the upper triangular factor U
+    pub fn u(&self) -> DMatrix<T> {
+        let n = self.lu.nrows();
+        DMatrix::from_fn(n, n, |i, j| if i <= j { self.lu[(i, j)] } else { T::ZERO })
+    }
+
+    /// Returns the row permutation, row i of P * A is row permutation()[ i ] of A
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Returns the permutation matrix P
+    pub fn p(&self) -> DMatrix<T> {
+        let n = self.perm.len();
+        DMatrix::from_fn(
+            n,
+            n,
+            |i, j| if self.perm[i] == j { T::ONE } else { T::ZERO },
+        )
+    }
+
+    /// Returns true if the decomposed matrix is singular, to within a tolerance scaled by its largest entry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 2, vec![ 1.0, 2.0, 2.0, 4.0 ] ).unwrap();
+    ///
+    /// assert!( a.lu().unwrap().is_singular() );
+    /// ```
+    ///
+    pub fn is_singular(&self) -> bool {
+        (0..self.lu.nrows()).any(|i| self.lu[(i, i)].abs() <= self.tolerance)
+    }
+
+    /// Calculates the determinant of the decomposed matrix from the diagonal of U
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 3, 3, vec![ 1.0, 2.0, 3.0, 0.0, 1.0, 4.0, 5.0, 6.0, 0.0 ] ).unwrap();
+    ///
+    /// assert!( ( a.lu().unwrap().determinant() - 1.0_f64 ).abs() < 1e-12 );
+    /// ```
+    ///
+    pub fn determinant(&self) -> T {
+        let det = (0..self.lu.nrows()).fold(T::ONE, |acc, i| acc * self.lu[(i, i)]);
+        if self.swaps & 1 == 0 {
+            det
+        } else {
+            -det
+        }
+    }
+
+    /// Solves A * x = b for x, where b has one column per right hand side
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// // x + y = 3, x - y = 1 and x + y = 10, x - y = 0
+    /// let a = DMatrix::new( 2, 2, vec![ 1.0, 1.0, 1.0, -1.0 ] ).unwrap();
+    /// let b = DMatrix::new( 2, 2, vec![ 3.0, 10.0, 1.0, 0.0 ] ).unwrap();
+    /// let x = a.lu().unwrap().solve( &b ).unwrap();
+    ///
+    /// for ( found, expected ) in x.as_slice().iter().zip( [ 2.0, 5.0, 1.0, 5.0 ] ) {
+    ///     assert!( ( found - expected ).abs() < 1e-12 );
+    /// }
+    /// ```
+    ///
+    pub fn solve(&self, b: &DMatrix<T>) -> Result<DMatrix<T>, MatrixError> {
+        let n = self.lu.nrows();
+        if b.nrows() != n {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (n, b.ncols()),
+                found: b.shape(),
+            });
+        }
+        if self.is_singular() {
+            return Err(MatrixError::Singular);
+        }
+
+        let mut x = DMatrix::from_fn(n, b.ncols(), |i, j| b[(self.perm[i], j)]);
+        for col in 0..b.ncols() {
+            /* Forward substitution with the unit lower triangle */
+            for i in 0..n {
+                let mut sum = x[(i, col)];
+                for k in 0..i {
+                    sum -= self.lu[(i, k)] * x[(k, col)];
+                }
+                x[(i, col)] = sum;
+            }
+            /* Back substitution with the upper triangle */
+            for i in (0..n).rev() {
+                let mut sum = x[(i, col)];
+                for k in (i + 1)..n {
+                    sum -= self.lu[(i, k)] * x[(k, col)];
+                }
+                x[(i, col)] = sum / self.lu[(i, i)];
+            }
+        }
+        Ok(x)
+    }
+
+    /// Solves A * x = b for x, where b is a single right hand side vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 2, vec![ 1.0, 2.0, 2.0, 4.0 ] ).unwrap();
+    ///
+    /// assert_eq!( a.lu().unwrap().solve_vec( &[ 1.0, 2.0 ] ), Err( MatrixError::Singular ) );
+    /// ```
+    ///
+    pub fn solve_vec(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        self.solve(&DMatrix::from_column(b)).map(DMatrix::into_vec)
+    }
+
+    /// Calculates the inverse of the decomposed matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 2, vec![ 4.0, 7.0, 2.0, 6.0 ] ).unwrap();
+    /// let inv = a.lu().unwrap().inverse().unwrap();
+    ///
+    /// for ( found, expected ) in inv.as_slice().iter().zip( [ 0.6, -0.7, -0.2, 0.4 ] ) {
+    ///     assert!( ( found - expected ).abs() < 1e-12 );
+    /// }
+    /// ```
+    ///
+    pub fn inverse(&self) -> Result<DMatrix<T>, MatrixError> {
+        self.solve(&DMatrix::identity(self.lu.nrows()))
+    }
+}
@@ -0,0 +1,264 @@
+use super::{DMatrix, MatrixError};
+use crate::linear::scalar::Float;
+
+/// The Householder QR decomposition of a m x n matrix, such that A * P = Q * R
+///
+/// Q is orthogonal and R is upper triangular. P is the identity unless the decomposition was
+/// made with column pivoting, in which case the diagonal of R decreases in magnitude and reveals the rank
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = DMatrix::new( 3, 2, vec![ 1.0, 1.0, 1.0, 2.0, 1.0, 3.0 ] ).unwrap();
+/// let qr = a.qr();
+/// let rebuilt = qr.q().try_mul( &qr.r() ).unwrap();
+///
+/// for ( found, expected ) in rebuilt.as_slice().iter().zip( a.as_slice() ) {
+///     assert!( ( found - expected ).abs() < 1e-12 );
+/// }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Qr<T> {
+    /// The upper triangle of the reduced matrix, the lower triangle is left as zero
+    r: DMatrix<T>,
+    /// The unit Householder vectors, reflector k acts on rows k and below
+    reflectors: Vec<Vec<T>>,
+    /// Column j of A * P is column perm[ j ] of A
+    perm: Vec<usize>,
+    /// Whether the decomposition was made with column pivoting
+    pivoted: bool,
+}
+
+impl<T: Float> DMatrix<T> {
+    /// Calculates the Householder QR decomposition of self
+    pub fn qr(&self) -> Qr<T> {
+        householder(self, false)
+    }
+
+    /// Calculates the Householder QR decomposition of self with column pivoting
+    ///
+    /// The pivoting moves the column with the largest remaining norm forward at each step, which makes
+    /// the decomposition rank revealing and lets least squares problems with dependent columns be solved
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// // The third column is the sum of the first two
+    /// let a = DMatrix::new( 4, 3, vec![
+    ///     1.0, 0.0, 1.0,
+    ///     0.0, 1.0, 1.0,
+    ///     1.0, 1.0, 2.0,
+    ///     2.0, 1.0, 3.0,
+    /// ] ).unwrap();
+    ///
+    /// assert_eq!( a.qr_col_piv().rank(), 2 );
+    /// ```
+    ///
+    pub fn qr_col_piv(&self) -> Qr<T> {
+        householder(self, true)
+    }
+}
+
+/* Reduces a to upper triangular form one column at a time with Householder reflections */
+fn householder<T: Float>(a: &DMatrix<T>, pivot: bool) -> Qr<T> {
+    let (m, n) = a.shape();
+    let mut r = a.clone();
+    let mut perm: Vec<usize> = (0..n).collect();
+    let mut reflectors = Vec::with_capacity(m.min(n));
+
+    for k in 0..m.min(n) {
+        if pivot {
+            let norm_sq = |j: usize| (k..m).fold(T::ZERO, |acc, i| acc + r[(i, j)] * r[(i, j)]);
+            let best = (k..n).fold(
+                k,
+                |best, j| if norm_sq(j) > norm_sq(best) { j } else { best },
+            );
+            if best != k {
+                for i in 0..m {
+                    let temp = r[(i, k)];
+                    r[(i, k)] = r[(i, best)];
+                    r[(i, best)] = temp;
+                }
+                perm.swap(k, best);
+            }
+        }
+
+        /* Build the reflector which maps column k below the diagonal onto a multiple of the first unit vector */
+        let mut v: Vec<T> = (k..m).map(|i| r[(i, k)]).collect();
+        let norm = v.iter().fold(T::ZERO, |acc, x| acc.hypot(*x));
+        if norm == T::ZERO {
+            reflectors.push(vec![T::ZERO; m - k]);
+            continue;
+        }
+
+        /* Choosing the sign opposite to the leading element avoids cancellation */
+        let alpha = if v[0] > T::ZERO { -norm } else { norm };
+        v[0] -= alpha;
+        let v_norm = v.iter().fold(T::ZERO, |acc, x| acc.hypot(*x));
+        v.iter_mut().for_each(|x| *x /= v_norm);
+
+        for j in k..n {
+            let dot = v
+                .iter()
+                .enumerate()
+                .fold(T::ZERO, |acc, (i, vi)| acc + *vi * r[(k + i, j)]);
+            for (i, vi) in v.iter().enumerate() {
+                r[(k + i, j)] -= (T::ONE + T::ONE) * *vi * dot;
+            }
+        }
+        /* Clean up the rounding left below the diagonal */
+        r[(k, k)] = alpha;
+        for i in (k + 1)..m {
+            r[(i, k)] = T::ZERO;
+        }
+
+        reflectors.push(v);
+    }
+
+    Qr {
+        r,
+        reflectors,
+        perm,
+        pivoted: pivot,
+    }
+}
+
+impl<T: Float> Qr<T> {
+    /// Returns the m x m orthogonal factor Q
+    pub fn q(&self) -> DMatrix<T> {
+        let m = self.r.nrows();
+        let mut q = DMatrix::identity(m);
+        /* Q = H0 * H1 * ... , so apply the reflectors to the identity from the last to the first */
+        for (k, v) in self.reflectors.iter().enumerate().rev() {
+            for j in 0..m {
+                let dot = v
+                    .iter()
+                    .enumerate()
+                    .fold(T::ZERO, |acc, (i, vi)| acc + *vi * q[(k + i, j)]);
+                for (i, vi) in v.iter().enumerate() {
+                    q[(k + i, j)] -= (T::ONE + T::ONE) * *vi * dot;
+                }
+            }
+        }
+        q
+    }
+
+    /// Returns the m x n upper triangular factor R
+    pub fn r(&self) -> DMatrix<T> {
+        self.r.clone()
+    }
+
+    /// Returns the column permutation, column j of A * P is column permutation()[ j ] of A
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Returns the numerical rank, the number of diagonal elements of R above a tolerance scaled by the largest one
+    ///
+    /// Only reliable when the decomposition was made with column pivoting
+    pub fn rank(&self) -> usize {
+        let tolerance = self.tolerance();
+        (0..self.r.nrows().min(self.r.ncols()))
+            .filter(|&i| self.r[(i, i)].abs() > tolerance)
+            .count()
+    }
+
+    /* Diagonal elements of R at or below this are treated as zero */
+    fn tolerance(&self) -> T {
+        let (m, n) = self.r.shape();
+        let largest = (0..m.min(n)).fold(T::ZERO, |acc, i| acc.max(self.r[(i, i)].abs()));
+        T::EPSILON * T::from_f64(m.max(n) as f64) * largest
+    }
+
+    /// Calculates Q^T * b
+    pub fn qt_mul(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        if b.len() != self.r.nrows() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.r.nrows(), 1),
+                found: (b.len(), 1),
+            });
+        }
+
+        let mut y = b.to_vec();
+        for (k, v) in self.reflectors.iter().enumerate() {
+            let dot = v
+                .iter()
+                .zip(y[k..].iter())
+                .fold(T::ZERO, |acc, (vi, yi)| acc + *vi * *yi);
+            for (vi, yi) in v.iter().zip(y[k..].iter_mut()) {
+                *yi -= (T::ONE + T::ONE) * *vi * dot;
+            }
+        }
+        Ok(y)
+    }
+
+    /// Finds the x which minimizes || A * x - b ||
+    ///
+    /// Without column pivoting A must have full column rank. With column pivoting the columns beyond
+    /// the numerical rank are given a weight of zero, producing a basic solution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// // The second column duplicates the first, so only their sum is determined
+    /// let a = DMatrix::new( 3, 2, vec![ 1.0, 1.0, 2.0, 2.0, 3.0, 3.0 ] ).unwrap();
+    /// let b = [ 2.0, 4.0, 6.0 ];
+    ///
+    /// assert_eq!( a.qr().solve( &b ), Err( MatrixError::RankDeficient { rank: 1 } ) );
+    ///
+    /// let x = a.qr_col_piv().solve( &b ).unwrap();
+    /// assert!( ( x[ 0 ] + x[ 1 ] - 2.0_f64 ).abs() < 1e-12 );
+    /// ```
+    ///
+    pub fn solve(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        let n = self.r.ncols();
+        let rank = self.rank();
+        if rank < n && !self.pivoted {
+            return Err(MatrixError::RankDeficient { rank });
+        }
+
+        let y = self.qt_mul(b)?;
+
+        /* Back substitution with the leading rank x rank block of R */
+        let mut z = vec![T::ZERO; n];
+        for i in (0..rank).rev() {
+            let sum = (i + 1..rank).fold(y[i], |acc, k| acc - self.r[(i, k)] * z[k]);
+            z[i] = sum / self.r[(i, i)];
+        }
+
+        let mut x = vec![T::ZERO; n];
+        for (j, p) in self.perm.iter().enumerate() {
+            x[*p] = z[j];
+        }
+        Ok(x)
+    }
+}
+
+/// Finds the x which minimizes || A * x - b || using a Householder QR decomposition of A
+///
+/// This avoids forming A^T * A, which would square the condition number of the problem.
+/// A must have full column rank, use DMatrix::qr_col_piv for problems with dependent columns
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// // Fit y = c0 + c1 * t through ( 0, 1 ), ( 1, 3 ), ( 2, 4 ), ( 3, 4 )
+/// let a = DMatrix::new( 4, 2, vec![ 1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0 ] ).unwrap();
+/// let c = least_squares( &a, &[ 1.0, 3.0, 4.0, 4.0 ] ).unwrap();
+///
+/// assert!( ( c[ 0 ] - 1.5_f64 ).abs() < 1e-12 );
+/// assert!( ( c[ 1 ] - 1.0_f64 ).abs() < 1e-12 );
+/// ```
+///
+pub fn least_squares<T: Float>(a: &DMatrix<T>, b: &[T]) -> Result<Vec<T>, MatrixError> {
+    a.qr().solve(b)
+}
@@ -0,0 +1,323 @@
+use super::{DMatrix, MatrixError};
+use crate::linear::scalar::Float;
+
+/* The number of sweeps after which the one sided Jacobi iteration gives up */
+const MAX_SVD_SWEEPS: usize = 100;
+
+/// The thin singular value decomposition of a m x n matrix, such that A = U * diag( singular_values ) * V^T
+///
+/// With k = min( m, n ), U is m x k, V^T is k x n and the singular values are sorted in descending order.
+/// The columns of U and rows of V^T are always orthonormal, those of zero singular values complete the basis
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = DMatrix::new( 3, 2, vec![ 3.0, 0.0, 0.0, -2.0, 0.0, 0.0 ] ).unwrap();
+/// let svd = a.svd().unwrap();
+///
+/// assert!( ( svd.singular_values[ 0 ] - 3.0_f64 ).abs() < 1e-12 );
+/// assert!( ( svd.singular_values[ 1 ] - 2.0_f64 ).abs() < 1e-12 );
+///
+/// let rebuilt = svd.low_rank_approx( 2 );
+/// for ( found, expected ) in rebuilt.as_slice().iter().zip( a.as_slice() ) {
+///     assert!( ( found - expected ).abs() < 1e-12 );
+/// }
+///
+/// // A rank deficient matrix still has orthonormal left singular vectors
+/// let b = DMatrix::new( 3, 2, vec![ 1.0, 0.0, 1.0, 0.0, 1.0, 0.0 ] ).unwrap();
+/// let u = b.svd().unwrap().u;
+/// let utu = u.transpose().try_mul( &u ).unwrap();
+///
+/// for ( found, expected ) in utu.as_slice().iter().zip( DMatrix::<f64>::identity( 2 ).as_slice() ) {
+///     assert!( ( found - expected ).abs() < 1e-12 );
+/// }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Svd<T> {
+    /// The left singular vectors, stored as the columns of the matrix
+    pub u: DMatrix<T>,
+    /// The singular values, in descending order
+    pub singular_values: Vec<T>,
+    /// The right singular vectors, stored as the rows of the matrix
+    pub v_t: DMatrix<T>,
+}
+
+impl<T: Float> DMatrix<T> {
+    /// Calculates the thin singular value decomposition of self with the one sided Jacobi method
+    pub fn svd(&self) -> Result<Svd<T>, MatrixError> {
+        if self.nrows() < self.ncols() {
+            /* Decompose the transpose so there are at least as many rows as columns, then swap the factors */
+            let svd = self.transpose().svd()?;
+            return Ok(Svd {
+                u: svd.v_t.transpose(),
+                singular_values: svd.singular_values,
+                v_t: svd.u.transpose(),
+            });
+        }
+
+        let (m, n) = self.shape();
+        let mut u = self.clone();
+        let mut v = DMatrix::identity(n);
+        let two = T::ONE + T::ONE;
+        /* Columns whose squared norm falls below this are rounding noise from a rank deficient matrix */
+        let frobenius_sq = self.as_slice().iter().fold(T::ZERO, |acc, x| acc + *x * *x);
+        let negligible = T::EPSILON * T::EPSILON * frobenius_sq;
+
+        let mut converged = false;
+        for _ in 0..MAX_SVD_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let (mut alpha, mut beta, mut gamma) = (T::ZERO, T::ZERO, T::ZERO);
+                    for i in 0..m {
+                        alpha += u[(i, p)] * u[(i, p)];
+                        beta += u[(i, q)] * u[(i, q)];
+                        gamma += u[(i, p)] * u[(i, q)];
+                    }
+                    if alpha <= negligible
+                        || beta <= negligible
+                        || gamma.abs() <= T::EPSILON * (alpha * beta).sqrt()
+                    {
+                        continue;
+                    }
+                    rotated = true;
+
+                    /* Rotate columns p and q so that they become orthogonal */
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let t = if zeta >= T::ZERO { T::ONE } else { -T::ONE }
+                        / (zeta.abs() + zeta.hypot(T::ONE));
+                    let c = T::ONE / t.hypot(T::ONE);
+                    let s = c * t;
+                    for i in 0..m {
+                        let (uip, uiq) = (u[(i, p)], u[(i, q)]);
+                        u[(i, p)] = c * uip - s * uiq;
+                        u[(i, q)] = s * uip + c * uiq;
+                    }
+                    for i in 0..n {
+                        let (vip, viq) = (v[(i, p)], v[(i, q)]);
+                        v[(i, p)] = c * vip - s * viq;
+                        v[(i, q)] = s * vip + c * viq;
+                    }
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(MatrixError::NoConvergence);
+        }
+
+        /* The column norms are the singular values, normalizing the columns leaves the left singular vectors */
+        let norms: Vec<T> = (0..n)
+            .map(|j| (0..m).fold(T::ZERO, |acc, i| acc.hypot(u[(i, j)])))
+            .collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| {
+            norms[j]
+                .partial_cmp(&norms[i])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut left = DMatrix::from_fn(m, n, |i, j| {
+            let norm = norms[order[j]];
+            if norm == T::ZERO {
+                T::ZERO
+            } else {
+                u[(i, order[j])] / norm
+            }
+        });
+        let rank = norms
+            .iter()
+            .filter(|norm| **norm * **norm > negligible)
+            .count();
+        complete_basis(&mut left, rank);
+
+        Ok(Svd {
+            u: left,
+            singular_values: order.iter().map(|&j| norms[j]).collect(),
+            v_t: DMatrix::from_fn(n, n, |i, j| v[(j, order[i])]),
+        })
+    }
+
+    /// Calculates the Moore-Penrose pseudo-inverse of self, see Svd::pinv
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 1, vec![ 1.0, 1.0 ] ).unwrap();
+    /// let pinv = a.pinv( None ).unwrap();
+    ///
+    /// assert_eq!( pinv.shape(), ( 1, 2 ) );
+    /// assert!( ( pinv[ ( 0, 0 ) ] - 0.5_f64 ).abs() < 1e-12 );
+    /// ```
+    ///
+    pub fn pinv(&self, tolerance: Option<T>) -> Result<DMatrix<T>, MatrixError> {
+        Ok(self.svd()?.pinv(tolerance))
+    }
+
+    /// Calculates the numerical rank of self, see Svd::rank
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 3, 3, vec![ 1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0 ] ).unwrap();
+    ///
+    /// assert_eq!( a.rank( None ).unwrap(), 2 );
+    /// ```
+    ///
+    pub fn rank(&self, tolerance: Option<T>) -> Result<usize, MatrixError> {
+        Ok(self.svd()?.rank(tolerance))
+    }
+
+    /// Calculates the 2-norm condition number of self, see Svd::condition_number
+    pub fn condition_number(&self) -> Result<T, MatrixError> {
+        Ok(self.svd()?.condition_number())
+    }
+}
+
+/* Replaces the columns of u from rank on, which belong to zero singular values, with unit vectors
+orthogonal to every column before them. Each is built from the standard basis vector with the most
+left over after removing its projection onto the columns already in place */
+fn complete_basis<T: Float>(u: &mut DMatrix<T>, rank: usize) {
+    let (m, n) = u.shape();
+
+    for j in rank..n {
+        let mut best = vec![T::ZERO; m];
+        let mut best_norm = T::ZERO;
+        for candidate in 0..m {
+            let mut v = vec![T::ZERO; m];
+            v[candidate] = T::ONE;
+
+            /* Orthogonalize twice, the second pass removes what rounding left of the first */
+            for _ in 0..2 {
+                for k in 0..j {
+                    let dot = (0..m).fold(T::ZERO, |acc, i| acc + u[(i, k)] * v[i]);
+                    for (i, vi) in v.iter_mut().enumerate() {
+                        *vi -= dot * u[(i, k)];
+                    }
+                }
+            }
+
+            let norm = v.iter().fold(T::ZERO, |acc, x| acc.hypot(*x));
+            if norm > best_norm {
+                best = v;
+                best_norm = norm;
+            }
+        }
+
+        for (i, vi) in best.iter().enumerate() {
+            u[(i, j)] = *vi / best_norm;
+        }
+    }
+}
+
+impl<T: Float> Svd<T> {
+    /* Singular values at or below this are treated as zero when no tolerance is entered */
+    fn default_tolerance(&self) -> T {
+        let largest = self.singular_values.first().copied().unwrap_or(T::ZERO);
+        let size = self.u.nrows().max(self.v_t.ncols());
+        T::EPSILON * T::from_f64(size as f64) * largest
+    }
+
+    /// Returns the number of singular values above the tolerance
+    ///
+    /// When no tolerance is entered, epsilon * max( m, n ) * the largest singular value is used
+    pub fn rank(&self, tolerance: Option<T>) -> usize {
+        let tolerance = tolerance.unwrap_or_else(|| self.default_tolerance());
+        self.singular_values
+            .iter()
+            .filter(|sigma| **sigma > tolerance)
+            .count()
+    }
+
+    /// Calculates the Moore-Penrose pseudo-inverse, V * diag( 1 / sigma ) * U^T
+    ///
+    /// Singular values at or below the tolerance are treated as zero, see Svd::rank for the default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// // The pseudo-inverse of an invertible matrix is its inverse
+    /// let a = DMatrix::new( 2, 2, vec![ 4.0, 7.0, 2.0, 6.0 ] ).unwrap();
+    /// let pinv = a.svd().unwrap().pinv( None );
+    ///
+    /// for ( found, expected ) in pinv.as_slice().iter().zip( [ 0.6, -0.7, -0.2, 0.4 ] ) {
+    ///     assert!( ( found - expected ).abs() < 1e-12 );
+    /// }
+    /// ```
+    ///
+    pub fn pinv(&self, tolerance: Option<T>) -> DMatrix<T> {
+        let tolerance = tolerance.unwrap_or_else(|| self.default_tolerance());
+        let k = self.singular_values.len();
+        let (m, n) = (self.u.nrows(), self.v_t.ncols());
+
+        DMatrix::from_fn(n, m, |i, j| {
+            (0..k)
+                .filter(|&l| self.singular_values[l] > tolerance)
+                .fold(T::ZERO, |acc, l| {
+                    acc + self.v_t[(l, i)] * self.u[(j, l)] / self.singular_values[l]
+                })
+        })
+    }
+
+    /// Calculates the 2-norm condition number, the ratio of the largest to the smallest singular value
+    ///
+    /// The result is infinite when the matrix is singular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 2, vec![ 10.0, 0.0, 0.0, 0.5 ] ).unwrap();
+    ///
+    /// assert!( ( a.condition_number().unwrap() - 20.0_f64 ).abs() < 1e-12 );
+    ///
+    /// let zero = DMatrix::<f64>::zeros( 2, 2 );
+    /// assert_eq!( zero.condition_number().unwrap(), f64::INFINITY );
+    /// ```
+    ///
+    pub fn condition_number(&self) -> T {
+        match (self.singular_values.first(), self.singular_values.last()) {
+            (Some(_), Some(smallest)) if *smallest == T::ZERO => T::INFINITY,
+            (Some(largest), Some(smallest)) => *largest / *smallest,
+            _ => T::ZERO,
+        }
+    }
+
+    /// Rebuilds the matrix from only its k largest singular values, the closest rank k matrix in the 2-norm
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = DMatrix::new( 2, 2, vec![ 2.0, 0.0, 0.0, 0.1 ] ).unwrap();
+    /// let approx = a.svd().unwrap().low_rank_approx( 1 );
+    ///
+    /// assert!( ( approx[ ( 0, 0 ) ] - 2.0_f64 ).abs() < 1e-12 );
+    /// assert!( approx[ ( 1, 1 ) ].abs() < 1e-12 );
+    /// ```
+    ///
+    pub fn low_rank_approx(&self, k: usize) -> DMatrix<T> {
+        let k = k.min(self.singular_values.len());
+        let (m, n) = (self.u.nrows(), self.v_t.ncols());
+
+        DMatrix::from_fn(m, n, |i, j| {
+            (0..k).fold(T::ZERO, |acc, l| {
+                acc + self.u[(i, l)] * self.singular_values[l] * self.v_t[(l, j)]
+            })
+        })
+    }
+}
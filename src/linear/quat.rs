@@ -0,0 +1,469 @@
+use super::matrix::{Mat3, Mat4};
+use super::vector::Vec3;
+use super::UnitF;
+
+use std::ops::{Mul, MulAssign, Neg};
+
+/// A quaternion w + xi + yj + zk, used as a unit quaternion to represent 3D rotations
+///
+/// Unlike Vec3::rotate, rotations stored as quaternions compose without gimbal lock and can
+/// represent any orientation. Rotations follow the right hand rule, matching Mat3 acting on column vectors
+///
+/// # Examples
+///
+/// ```
+/// use std::f32::consts::FRAC_PI_2;
+/// use miscmath::prelude::*;
+///
+/// let quarter_z = Quat::from_axis_angle( &Vec3::new( &0.0, &0.0, &1.0 ), &FRAC_PI_2 );
+/// let quarter_x = Quat::from_axis_angle( &Vec3::new( &1.0, &0.0, &0.0 ), &FRAC_PI_2 );
+///
+/// // Rotate about z first, then about x
+/// let both = quarter_x * quarter_z;
+///
+/// assert_eq!( both * Vec3::new( &1.0, &0.0, &0.0 ), Vec3::new( &0.0, &0.0, &1.0 ) );
+/// assert_eq!( both * both.inverse().unwrap(), Quat::identity() );
+/// ```
+///
+#[derive(Copy, Clone, Debug)]
+pub struct Quat {
+    /// The real part
+    pub w: f32,
+    /// The i component
+    pub x: f32,
+    /// The j component
+    pub y: f32,
+    /// The k component
+    pub z: f32,
+}
+
+/// Implements Default for Quat
+/// Generates the identity rotation and returns it
+impl Default for Quat {
+    fn default() -> Self {
+        Quat::identity()
+    }
+}
+
+/// Implements Mul for Quat, the Hamilton product
+///
+/// The product a * b applies the rotation b first, then a
+impl Mul for Quat {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Quat {
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        }
+    }
+}
+
+/// Implements MulAssign for Quat
+impl MulAssign for Quat {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Implements Mul of a Vec3 by Quat, rotating the Vec3, see Quat::rotate
+impl Mul<Vec3> for Quat {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        self.rotate(&rhs)
+    }
+}
+
+/// Implements Neg for Quat, which represents the same rotation
+impl Neg for Quat {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Quat {
+            w: -self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+/// Implements PartialEq for Quat, comparing components with a tolerance
+///
+/// q and -q represent the same rotation but are not equal
+impl PartialEq for Quat {
+    fn eq(&self, rhs: &Self) -> bool {
+        (self.w - rhs.w).abs() < 0.00001
+            && (self.x - rhs.x).abs() < 0.00001
+            && (self.y - rhs.y).abs() < 0.00001
+            && (self.z - rhs.z).abs() < 0.00001
+    }
+}
+
+impl Quat {
+    /// Generates a new instance of Quat initialized to chosen values and returns it
+    pub fn new(w: &f32, x: &f32, y: &f32, z: &f32) -> Quat {
+        Quat {
+            w: *w,
+            x: *x,
+            y: *y,
+            z: *z,
+        }
+    }
+
+    /// Generates the identity rotation and returns it
+    pub fn identity() -> Quat {
+        Quat::new(&1.0, &0.0, &0.0, &0.0)
+    }
+
+    /// Generates a rotation of angle radians around axis and returns it, axis need not be normalized
+    ///
+    /// A zero axis gives the identity
+    pub fn from_axis_angle(axis: &Vec3, angle: &f32) -> Quat {
+        let mag = axis.mag();
+        if mag == 0.0 {
+            return Quat::identity();
+        }
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        let scale = sin / mag;
+        Quat::new(
+            &cos,
+            &(axis.x * scale),
+            &(axis.y * scale),
+            &(axis.z * scale),
+        )
+    }
+
+    /// Generates a rotation from Euler angles in radians and returns it
+    ///
+    /// The rotation applies roll around x, then pitch around y, then yaw around z, all about the fixed axes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Quat::from_euler( &0.3, &-0.4, &1.2 );
+    /// let ( roll, pitch, yaw ) = a.to_euler();
+    ///
+    /// assert!( ( roll - 0.3 ).abs() < 0.0001 && ( pitch + 0.4 ).abs() < 0.0001 && ( yaw - 1.2 ).abs() < 0.0001 );
+    ///
+    /// let x = Quat::from_axis_angle( &Vec3::new( &1.0, &0.0, &0.0 ), &0.3 );
+    /// let y = Quat::from_axis_angle( &Vec3::new( &0.0, &1.0, &0.0 ), &-0.4 );
+    /// let z = Quat::from_axis_angle( &Vec3::new( &0.0, &0.0, &1.0 ), &1.2 );
+    ///
+    /// assert_eq!( a, z * y * x );
+    /// ```
+    ///
+    pub fn from_euler(roll: &f32, pitch: &f32, yaw: &f32) -> Quat {
+        let (sr, cr) = (roll * 0.5).sin_cos();
+        let (sp, cp) = (pitch * 0.5).sin_cos();
+        let (sy, cy) = (yaw * 0.5).sin_cos();
+
+        Quat {
+            w: cr * cp * cy + sr * sp * sy,
+            x: sr * cp * cy - cr * sp * sy,
+            y: cr * sp * cy + sr * cp * sy,
+            z: cr * cp * sy - sr * sp * cy,
+        }
+    }
+
+    /// Generates a rotation from a rotation matrix and returns it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Quat::from_euler( &2.5, &0.7, &-2.9 );
+    /// let b = Quat::from_mat3( &a.to_mat3() );
+    ///
+    /// // Both signs represent the same rotation, from_mat3 picks the one with a non-negative w
+    /// assert!( b == a || b == -a );
+    /// assert!( b.w >= 0.0 );
+    /// ```
+    ///
+    pub fn from_mat3(mat: &Mat3) -> Quat {
+        let m = &mat.m;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        /* Divide by the largest of the four candidates to keep the result accurate */
+        let mut quat = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quat::new(
+                &(0.25 * s),
+                &((m[2][1] - m[1][2]) / s),
+                &((m[0][2] - m[2][0]) / s),
+                &((m[1][0] - m[0][1]) / s),
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.0;
+            Quat::new(
+                &((m[2][1] - m[1][2]) / s),
+                &(0.25 * s),
+                &((m[0][1] + m[1][0]) / s),
+                &((m[0][2] + m[2][0]) / s),
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.0;
+            Quat::new(
+                &((m[0][2] - m[2][0]) / s),
+                &((m[0][1] + m[1][0]) / s),
+                &(0.25 * s),
+                &((m[1][2] + m[2][1]) / s),
+            )
+        } else {
+            let s = (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.0;
+            Quat::new(
+                &((m[1][0] - m[0][1]) / s),
+                &((m[0][2] + m[2][0]) / s),
+                &((m[1][2] + m[2][1]) / s),
+                &(0.25 * s),
+            )
+        };
+
+        /* Keep w non-negative so equal rotations give equal quaternions */
+        if quat.w < 0.0 {
+            quat = -quat;
+        }
+        quat
+    }
+
+    /// Generates a rotation from the upper left 3x3 of a transform matrix and returns it
+    pub fn from_mat4(mat: &Mat4) -> Quat {
+        let m = &mat.m;
+        Quat::from_mat3(&Mat3::from_rows(&[
+            [m[0][0], m[0][1], m[0][2]],
+            [m[1][0], m[1][1], m[1][2]],
+            [m[2][0], m[2][1], m[2][2]],
+        ]))
+    }
+
+    /// Returns the rotation as an axis and an angle in radians, the axis is +x for the identity
+    pub fn to_axis_angle(&self) -> (Vec3, f32) {
+        let mut quat = *self;
+        quat.norm();
+        if quat.w < 0.0 {
+            quat = -quat;
+        }
+
+        let sin = (quat.x * quat.x + quat.y * quat.y + quat.z * quat.z).sqrt();
+        if sin < f32::EPSILON {
+            return (Vec3::new(&1.0, &0.0, &0.0), 0.0);
+        }
+        let axis = Vec3::new(&(quat.x / sin), &(quat.y / sin), &(quat.z / sin));
+        (axis, 2.0 * sin.atan2(quat.w))
+    }
+
+    /// Returns the rotation as Euler angles ( roll, pitch, yaw ) in radians, see Quat::from_euler
+    ///
+    /// Pitch is in [ -pi / 2, pi / 2 ], roll and yaw are in [ -pi, pi ]
+    pub fn to_euler(&self) -> (f32, f32, f32) {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+
+        let roll = (2.0 * (w * x + y * z)).atan2(1.0 - 2.0 * (x * x + y * y));
+        let sin_pitch = (2.0 * (w * y - z * x)).clamp(-1.0, 1.0);
+        let yaw = (2.0 * (w * z + x * y)).atan2(1.0 - 2.0 * (y * y + z * z));
+
+        (roll, sin_pitch.asin(), yaw)
+    }
+
+    /// Generates the rotation matrix of self, which should be normalized
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Quat::from_axis_angle( &Vec3::new( &0.0, &0.0, &1.0 ), &FRAC_PI_2 );
+    ///
+    /// assert_eq!( a.to_mat3(), Mat3::from_rows( &[
+    ///     [ 0.0, -1.0, 0.0 ],
+    ///     [ 1.0, 0.0, 0.0 ],
+    ///     [ 0.0, 0.0, 1.0 ],
+    /// ] ) );
+    /// ```
+    ///
+    pub fn to_mat3(&self) -> Mat3 {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+
+        Mat3::from_rows(&[
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ])
+    }
+
+    /// Generates the 4x4 transform matrix of self, a rotation with no translation
+    pub fn to_mat4(&self) -> Mat4 {
+        let m = self.to_mat3().m;
+
+        Mat4::from_rows(&[
+            [m[0][0], m[0][1], m[0][2], 0.0],
+            [m[1][0], m[1][1], m[1][2], 0.0],
+            [m[2][0], m[2][1], m[2][2], 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns the conjugate of self, which is the inverse rotation when self is normalized
+    pub fn conjugate(&self) -> Quat {
+        Quat::new(&self.w, &-self.x, &-self.y, &-self.z)
+    }
+
+    /// Calculates the inverse of self, None if self is zero
+    pub fn inverse(&self) -> Option<Quat> {
+        let mag_sq = self.mag_sq();
+        if mag_sq == 0.0 {
+            return None;
+        }
+        let conj = self.conjugate();
+        Some(Quat::new(
+            &(conj.w / mag_sq),
+            &(conj.x / mag_sq),
+            &(conj.y / mag_sq),
+            &(conj.z / mag_sq),
+        ))
+    }
+
+    /// Calculates the dot product between self and a passed in Quat
+    pub fn dot(&self, rhs: &Quat) -> f32 {
+        self.w * rhs.w + self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// Calculates the magnitude of self
+    pub fn mag(&self) -> f32 {
+        self.mag_sq().sqrt()
+    }
+
+    /// Calculates the magnitude squared of self
+    pub fn mag_sq(&self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Normalizes the magnitude of self to 1, a zero quaternion is left unchanged
+    pub fn norm(&mut self) {
+        let mag = self.mag();
+        if mag != 0.0 {
+            self.w /= mag;
+            self.x /= mag;
+            self.y /= mag;
+            self.z /= mag;
+        }
+    }
+
+    /// Rotates a Vec3 by self, which should be normalized
+    pub fn rotate(&self, vec: &Vec3) -> Vec3 {
+        /* v' = v + 2w( u x v ) + 2u x ( u x v ), with u the vector part of self */
+        let (ux, uy, uz) = (self.x, self.y, self.z);
+        let tx = 2.0 * (uy * vec.z - uz * vec.y);
+        let ty = 2.0 * (uz * vec.x - ux * vec.z);
+        let tz = 2.0 * (ux * vec.y - uy * vec.x);
+
+        Vec3::new(
+            &(vec.x + self.w * tx + (uy * tz - uz * ty)),
+            &(vec.y + self.w * ty + (uz * tx - ux * tz)),
+            &(vec.z + self.w * tz + (ux * ty - uy * tx)),
+        )
+    }
+
+    /// Linearly interpolates between self and a passed in Quat along the shorter path, then normalizes
+    ///
+    /// Cheaper than slerp, but the angular speed is not constant
+    pub fn nlerp(&mut self, rhs: &Quat, amt: UnitF) {
+        let t = amt.value();
+        let rhs = if self.dot(rhs) < 0.0 { -*rhs } else { *rhs };
+
+        self.w += (rhs.w - self.w) * t;
+        self.x += (rhs.x - self.x) * t;
+        self.y += (rhs.y - self.y) * t;
+        self.z += (rhs.z - self.z) * t;
+        self.norm();
+    }
+
+    /// Spherically interpolates between self and a passed in Quat along the shorter path, at a constant angular speed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use miscmath::prelude::*;
+    ///
+    /// let z = Vec3::new( &0.0, &0.0, &1.0 );
+    /// let mut a = Quat::identity();
+    /// a.slerp( &Quat::from_axis_angle( &z, &( PI / 2.0 ) ), UnitF::new( 0.5 ) );
+    ///
+    /// assert_eq!( a, Quat::from_axis_angle( &z, &( PI / 4.0 ) ) );
+    /// ```
+    ///
+    pub fn slerp(&mut self, rhs: &Quat, amt: UnitF) {
+        let t = amt.value();
+        let mut cos = self.dot(rhs);
+        let rhs = if cos < 0.0 {
+            cos = -cos;
+            -*rhs
+        } else {
+            *rhs
+        };
+
+        /* Nearly parallel quaternions would divide by a tiny sine, where nlerp is accurate enough */
+        if cos > 0.9995 {
+            self.nlerp(&rhs, amt);
+            return;
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let a = ((1.0 - t) * angle).sin() / sin;
+        let b = (t * angle).sin() / sin;
+
+        self.w = a * self.w + b * rhs.w;
+        self.x = a * self.x + b * rhs.x;
+        self.y = a * self.y + b * rhs.y;
+        self.z = a * self.z + b * rhs.z;
+    }
+}
+
+/// Implements From<Quat> for Mat3, see Quat::to_mat3
+impl From<Quat> for Mat3 {
+    fn from(quat: Quat) -> Self {
+        quat.to_mat3()
+    }
+}
+
+/// Implements From<Quat> for Mat4, see Quat::to_mat4
+impl From<Quat> for Mat4 {
+    fn from(quat: Quat) -> Self {
+        quat.to_mat4()
+    }
+}
+
+/// Implements From<Mat3> for Quat, see Quat::from_mat3
+impl From<Mat3> for Quat {
+    fn from(mat: Mat3) -> Self {
+        Quat::from_mat3(&mat)
+    }
+}
+
+/// Implements From<Mat4> for Quat, see Quat::from_mat4
+impl From<Mat4> for Quat {
+    fn from(mat: Mat4) -> Self {
+        Quat::from_mat4(&mat)
+    }
+}
@@ -0,0 +1,242 @@
+use std::fmt::Debug;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// A numeric type which can be stored in the vectors and matrices of this crate
+///
+/// Implemented for every primitive integer and float type
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// fn sum<T: Scalar>( values: &[T] ) -> T {
+///     values.iter().fold( T::ZERO, | acc, v | acc + *v )
+/// }
+///
+/// assert_eq!( sum( &[ 1, 2, 3 ] ), 6 );
+/// assert_eq!( sum( &[ 0.5, 0.25 ] ), 0.75 );
+/// ```
+///
+pub trait Scalar:
+    Copy
+    + Debug
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+{
+    /// The additive identity
+    const ZERO: Self;
+    /// The multiplicative identity
+    const ONE: Self;
+
+    /// Returns true if self and other differ by less than the tolerance the vectors use for equality
+    ///
+    /// The tolerance is 0.00001 for floats, integers must be exactly equal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// assert!( 0.1_f32.approx_eq( 0.100001 ) );
+    /// assert!( !3.approx_eq( 4 ) );
+    /// ```
+    ///
+    fn approx_eq(self, other: Self) -> bool;
+}
+
+macro_rules! impl_scalar {
+    ($zero:literal, $one:literal; $($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+
+                fn approx_eq(self, other: Self) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+    ($zero:literal, $one:literal, $tolerance:literal; $($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+
+                fn approx_eq(self, other: Self) -> bool {
+                    (self - other).abs() < $tolerance
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_scalar!(0.0, 1.0, 0.00001; f32, f64);
+
+/// A floating point Scalar, needed by operations such as square roots, trigonometry and matrix decompositions
+///
+/// Implemented for f32 and f64
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// fn hypot<T: Float>( a: T, b: T ) -> T {
+///     ( a * a + b * b ).sqrt()
+/// }
+///
+/// assert_eq!( hypot( 3.0_f32, 4.0 ), 5.0 );
+/// assert_eq!( hypot( 3.0_f64, 4.0 ), 5.0 );
+/// ```
+///
+pub trait Float: Scalar + Neg<Output = Self> {
+    /// The difference between 1.0 and the next larger representable number
+    const EPSILON: Self;
+    /// Archimedes' constant
+    const PI: Self;
+    /// Positive infinity
+    const INFINITY: Self;
+
+    /// Converts a f64 into Self, rounding if needed
+    fn from_f64(value: f64) -> Self;
+    /// Converts self into a f64
+    fn to_f64(self) -> f64;
+    /// Returns the absolute value of self
+    fn abs(self) -> Self;
+    /// Returns the square root of self
+    fn sqrt(self) -> Self;
+    /// Returns the sine of self in radians
+    fn sin(self) -> Self;
+    /// Returns the cosine of self in radians
+    fn cos(self) -> Self;
+    /// Returns the tangent of self in radians
+    fn tan(self) -> Self;
+    /// Returns the arcsine of self in radians
+    fn asin(self) -> Self;
+    /// Returns the arccosine of self in radians
+    fn acos(self) -> Self;
+    /// Returns the four quadrant arctangent of self (y) and other (x) in radians
+    fn atan2(self, other: Self) -> Self;
+    /// Returns sqrt( self^2 + other^2 ) without undue overflow or underflow
+    fn hypot(self, other: Self) -> Self;
+    /// Raises self to an integer power
+    fn powi(self, n: i32) -> Self;
+    /// Raises self to a floating point power
+    fn powf(self, n: Self) -> Self;
+    /// Returns the largest integer less than or equal to self
+    fn floor(self) -> Self;
+    /// Returns the larger of self and other
+    fn max(self, other: Self) -> Self;
+    /// Returns the smaller of self and other
+    fn min(self, other: Self) -> Self;
+    /// Restricts self to the closed interval [min,max]
+    fn clamp(self, min: Self, max: Self) -> Self;
+    /// Returns 1.0 if self is positive or +0.0, and -1.0 if self is negative or -0.0
+    fn signum(self) -> Self;
+    /// Returns true if self is neither infinite nor NaN
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {
+        $(
+            impl Float for $t {
+                const EPSILON: Self = $t::EPSILON;
+                const PI: Self = std::$t::consts::PI;
+                const INFINITY: Self = $t::INFINITY;
+
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn abs(self) -> Self {
+                    $t::abs(self)
+                }
+
+                fn sqrt(self) -> Self {
+                    $t::sqrt(self)
+                }
+
+                fn sin(self) -> Self {
+                    $t::sin(self)
+                }
+
+                fn cos(self) -> Self {
+                    $t::cos(self)
+                }
+
+                fn tan(self) -> Self {
+                    $t::tan(self)
+                }
+
+                fn asin(self) -> Self {
+                    $t::asin(self)
+                }
+
+                fn acos(self) -> Self {
+                    $t::acos(self)
+                }
+
+                fn atan2(self, other: Self) -> Self {
+                    $t::atan2(self, other)
+                }
+
+                fn hypot(self, other: Self) -> Self {
+                    $t::hypot(self, other)
+                }
+
+                fn powi(self, n: i32) -> Self {
+                    $t::powi(self, n)
+                }
+
+                fn powf(self, n: Self) -> Self {
+                    $t::powf(self, n)
+                }
+
+                fn floor(self) -> Self {
+                    $t::floor(self)
+                }
+
+                fn max(self, other: Self) -> Self {
+                    $t::max(self, other)
+                }
+
+                fn min(self, other: Self) -> Self {
+                    $t::min(self, other)
+                }
+
+                fn clamp(self, min: Self, max: Self) -> Self {
+                    $t::clamp(self, min, max)
+                }
+
+                fn signum(self) -> Self {
+                    $t::signum(self)
+                }
+
+                fn is_finite(self) -> bool {
+                    $t::is_finite(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
@@ -0,0 +1,537 @@
+use super::matrix::{DMatrix, MatrixError};
+use super::scalar::Scalar;
+
+/* Sorts (major, minor, value) triplets, sums duplicates and builds the offsets / indices / values arrays of a compressed layout */
+fn compress<T: Scalar>(
+    major_dim: usize,
+    mut triplets: Vec<(usize, usize, T)>,
+) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+    triplets.sort_by_key(|&(major, minor, _)| (major, minor));
+
+    let mut offsets = vec![0; major_dim + 1];
+    let mut indices: Vec<usize> = Vec::with_capacity(triplets.len());
+    let mut values: Vec<T> = Vec::with_capacity(triplets.len());
+    let mut last: Option<(usize, usize)> = None;
+
+    for (major, minor, value) in triplets {
+        if last == Some((major, minor)) {
+            if let Some(sum) = values.last_mut() {
+                *sum += value;
+            }
+            continue;
+        }
+        last = Some((major, minor));
+        offsets[major + 1] += 1;
+        indices.push(minor);
+        values.push(value);
+    }
+
+    for i in 0..major_dim {
+        offsets[i + 1] += offsets[i];
+    }
+    (offsets, indices, values)
+}
+
+/// A sparse matrix in coordinate format, a list of ( row, column, value ) entries
+///
+/// Meant for building a matrix entry by entry before converting it to CsrMatrix or CscMatrix.
+/// Entries pushed more than once at the same position are summed on conversion
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let mut coo = CooMatrix::new( 2, 3 );
+/// coo.push( 0, 0, 1.0 ).unwrap();
+/// coo.push( 1, 2, 2.0 ).unwrap();
+/// coo.push( 1, 2, 3.0 ).unwrap();
+///
+/// let csr = coo.to_csr();
+///
+/// assert_eq!( csr.nnz(), 2 );
+/// assert_eq!( csr.get( 1, 2 ), Some( 5.0 ) );
+/// assert!( coo.push( 2, 0, 1.0 ).is_err() );
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CooMatrix<T> {
+    /// The number of rows
+    rows: usize,
+    /// The number of columns
+    cols: usize,
+    /// The ( row, column, value ) entries in the order they were pushed
+    entries: Vec<(usize, usize, T)>,
+}
+
+impl<T: Scalar> CooMatrix<T> {
+    /// Generates a new empty instance of CooMatrix with the shape entered and returns it
+    pub fn new(rows: usize, cols: usize) -> CooMatrix<T> {
+        CooMatrix {
+            rows,
+            cols,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Generates a new instance of CooMatrix holding the nonzero elements of a DMatrix
+    pub fn from_dense(mat: &DMatrix<T>) -> CooMatrix<T> {
+        let mut coo = CooMatrix::new(mat.nrows(), mat.ncols());
+        for i in 0..mat.nrows() {
+            for (j, value) in mat.row(i).iter().enumerate() {
+                if *value != T::ZERO {
+                    coo.entries.push((i, j, *value));
+                }
+            }
+        }
+        coo
+    }
+
+    /// Adds an entry at ( row, column ), failing if the position is outside of the matrix
+    pub fn push(&mut self, row: usize, col: usize, value: T) -> Result<(), MatrixError> {
+        if row >= self.rows || col >= self.cols {
+            return Err(MatrixError::OutOfBounds {
+                index: (row, col),
+                shape: self.shape(),
+            });
+        }
+        self.entries.push((row, col, value));
+        Ok(())
+    }
+
+    /// Returns the shape of self as ( rows, columns )
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the number of stored entries, including any duplicates
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the stored ( row, column, value ) entries
+    pub fn entries(&self) -> &[(usize, usize, T)] {
+        &self.entries
+    }
+
+    /// Converts self into compressed sparse row format
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let (row_offsets, col_indices, values) = compress(self.rows, self.entries.clone());
+        CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Converts self into compressed sparse column format
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let transposed = self.entries.iter().map(|&(i, j, v)| (j, i, v)).collect();
+        let (col_offsets, row_indices, values) = compress(self.cols, transposed);
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+
+    /// Converts self into a dense DMatrix
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let mut dense = DMatrix::zeros(self.rows, self.cols);
+        for &(i, j, value) in &self.entries {
+            dense[(i, j)] += value;
+        }
+        dense
+    }
+}
+
+/// A sparse matrix in compressed sparse row format
+///
+/// The column indices and values of row i are stored at row_offsets[ i ]..row_offsets[ i + 1 ],
+/// which makes row access and matrix-vector products fast
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// // A 1D spring chain stiffness matrix
+/// let mut coo = CooMatrix::new( 3, 3 );
+/// for i in 0..3 {
+///     coo.push( i, i, 2.0 ).unwrap();
+///     if i > 0 {
+///         coo.push( i, i - 1, -1.0 ).unwrap();
+///         coo.push( i - 1, i, -1.0 ).unwrap();
+///     }
+/// }
+/// let k = coo.to_csr();
+///
+/// assert_eq!( k.mul_vec( &[ 1.0, 1.0, 1.0 ] ).unwrap(), vec![ 1.0, 0.0, 1.0 ] );
+/// assert_eq!( k.transpose(), k );
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsrMatrix<T> {
+    /// The number of rows
+    rows: usize,
+    /// The number of columns
+    cols: usize,
+    /// Where each row starts in col_indices and values, with one extra entry marking the end
+    row_offsets: Vec<usize>,
+    /// The column of each stored value, sorted within each row
+    col_indices: Vec<usize>,
+    /// The stored values
+    values: Vec<T>,
+}
+
+impl<T: Scalar> CsrMatrix<T> {
+    /// Generates a new instance of CsrMatrix holding the nonzero elements of a DMatrix
+    pub fn from_dense(mat: &DMatrix<T>) -> CsrMatrix<T> {
+        CooMatrix::from_dense(mat).to_csr()
+    }
+
+    /// Generates a new n x n sparse identity matrix and returns it
+    pub fn identity(n: usize) -> CsrMatrix<T> {
+        CsrMatrix {
+            rows: n,
+            cols: n,
+            row_offsets: (0..=n).collect(),
+            col_indices: (0..n).collect(),
+            values: vec![T::ONE; n],
+        }
+    }
+
+    /// Returns the shape of self as ( rows, columns )
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the number of stored values
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the row offsets, column indices and values arrays
+    pub fn as_parts(&self) -> (&[usize], &[usize], &[T]) {
+        (&self.row_offsets, &self.col_indices, &self.values)
+    }
+
+    /// Returns the element at ( row, column ), which is zero when it isn't stored, or None if it is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let csr = CsrMatrix::from_dense( &DMatrix::new( 2, 2, vec![ 1.0, 0.0, 0.0, 4.0 ] ).unwrap() );
+    ///
+    /// assert_eq!( csr.get( 1, 1 ), Some( 4.0 ) );
+    /// assert_eq!( csr.get( 0, 1 ), Some( 0.0 ) );
+    /// assert_eq!( csr.get( 2, 0 ), None );
+    /// assert_eq!( csr.get( 0, 2 ), None );
+    /// ```
+    ///
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        match self.col_indices[range.clone()].binary_search(&col) {
+            Ok(k) => Some(self.values[range.start + k]),
+            Err(_) => Some(T::ZERO),
+        }
+    }
+
+    /// Returns an iterator over the ( column, value ) pairs stored in the row at index i
+    pub fn row_iter(&self, i: usize) -> impl Iterator<Item = (usize, T)> + '_ {
+        let range = self.row_offsets[i]..self.row_offsets[i + 1];
+        self.col_indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+
+    /// Returns an iterator over every stored ( row, column, value ) entry
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        (0..self.rows).flat_map(move |i| self.row_iter(i).map(move |(j, v)| (i, j, v)))
+    }
+
+    /// Multiplies self by the dense column vector rhs, rhs must have as many elements as self has columns
+    pub fn mul_vec(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+        if rhs.len() != self.cols {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.cols, 1),
+                found: (rhs.len(), 1),
+            });
+        }
+        Ok((0..self.rows)
+            .map(|i| {
+                self.row_iter(i)
+                    .fold(T::ZERO, |acc, (j, v)| acc + v * rhs[j])
+            })
+            .collect())
+    }
+
+    /// Generates the transpose of self and returns it
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        let transposed = self.iter().map(|(i, j, v)| (j, i, v)).collect();
+        let (row_offsets, col_indices, values) = compress(self.cols, transposed);
+        CsrMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Adds rhs to self, the shapes must match
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a: CsrMatrix<i32> = CsrMatrix::identity( 2 );
+    /// let b = a.try_add( &a ).unwrap();
+    ///
+    /// assert_eq!( b.get( 1, 1 ), Some( 2 ) );
+    /// assert_eq!( b.nnz(), 2 );
+    /// ```
+    ///
+    pub fn try_add(&self, rhs: &CsrMatrix<T>) -> Result<CsrMatrix<T>, MatrixError> {
+        if self.shape() != rhs.shape() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.shape(),
+                found: rhs.shape(),
+            });
+        }
+        let (row_offsets, col_indices, values) =
+            compress(self.rows, self.iter().chain(rhs.iter()).collect());
+        Ok(CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            row_offsets,
+            col_indices,
+            values,
+        })
+    }
+
+    /// Converts self into coordinate format
+    pub fn to_coo(&self) -> CooMatrix<T> {
+        CooMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self.iter().collect(),
+        }
+    }
+
+    /// Converts self into compressed sparse column format
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        self.to_coo().to_csc()
+    }
+
+    /// Converts self into a dense DMatrix
+    pub fn to_dense(&self) -> DMatrix<T> {
+        self.to_coo().to_dense()
+    }
+}
+
+/// A sparse matrix in compressed sparse column format
+///
+/// The row indices and values of column j are stored at col_offsets[ j ]..col_offsets[ j + 1 ],
+/// which makes column access and products with the transpose fast
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let dense = DMatrix::new( 2, 3, vec![ 1, 0, 2, 0, 3, 0 ] ).unwrap();
+/// let csc = CscMatrix::from_dense( &dense );
+///
+/// assert_eq!( csc.nnz(), 3 );
+/// assert_eq!( csc.mul_vec( &[ 1, 1, 1 ] ).unwrap(), vec![ 3, 3 ] );
+/// assert_eq!( csc.to_csr().to_dense(), dense );
+/// assert_eq!( csc.transpose().to_dense(), dense.transpose() );
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CscMatrix<T> {
+    /// The number of rows
+    rows: usize,
+    /// The number of columns
+    cols: usize,
+    /// Where each column starts in row_indices and values, with one extra entry marking the end
+    col_offsets: Vec<usize>,
+    /// The row of each stored value, sorted within each column
+    row_indices: Vec<usize>,
+    /// The stored values
+    values: Vec<T>,
+}
+
+impl<T: Scalar> CscMatrix<T> {
+    /// Generates a new instance of CscMatrix holding the nonzero elements of a DMatrix
+    pub fn from_dense(mat: &DMatrix<T>) -> CscMatrix<T> {
+        CooMatrix::from_dense(mat).to_csc()
+    }
+
+    /// Returns the shape of self as ( rows, columns )
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the number of stored values
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the column offsets, row indices and values arrays
+    pub fn as_parts(&self) -> (&[usize], &[usize], &[T]) {
+        (&self.col_offsets, &self.row_indices, &self.values)
+    }
+
+    /// Returns the element at ( row, column ), which is zero when it isn't stored, or None if it is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let csc = CscMatrix::from_dense( &DMatrix::new( 2, 2, vec![ 1.0, 0.0, 3.0, 0.0 ] ).unwrap() );
+    ///
+    /// assert_eq!( csc.get( 1, 0 ), Some( 3.0 ) );
+    /// assert_eq!( csc.get( 1, 1 ), Some( 0.0 ) );
+    /// assert_eq!( csc.get( 0, 2 ), None );
+    /// assert_eq!( csc.get( 2, 0 ), None );
+    /// ```
+    ///
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+
+        let range = self.col_offsets[col]..self.col_offsets[col + 1];
+        match self.row_indices[range.clone()].binary_search(&row) {
+            Ok(k) => Some(self.values[range.start + k]),
+            Err(_) => Some(T::ZERO),
+        }
+    }
+
+    /// Returns an iterator over the ( row, value ) pairs stored in the column at index j
+    pub fn col_iter(&self, j: usize) -> impl Iterator<Item = (usize, T)> + '_ {
+        let range = self.col_offsets[j]..self.col_offsets[j + 1];
+        self.row_indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+
+    /// Returns an iterator over every stored ( row, column, value ) entry
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        (0..self.cols).flat_map(move |j| self.col_iter(j).map(move |(i, v)| (i, j, v)))
+    }
+
+    /// Multiplies self by the dense column vector rhs, rhs must have as many elements as self has columns
+    pub fn mul_vec(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+        if rhs.len() != self.cols {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.cols, 1),
+                found: (rhs.len(), 1),
+            });
+        }
+        let mut out = vec![T::ZERO; self.rows];
+        for (i, j, v) in self.iter() {
+            out[i] += v * rhs[j];
+        }
+        Ok(out)
+    }
+
+    /// Generates the transpose of self and returns it
+    pub fn transpose(&self) -> CscMatrix<T> {
+        /* Grouping the entries by their row gives the columns of the transpose */
+        let (col_offsets, row_indices, values) = compress(self.rows, self.iter().collect());
+        CscMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+
+    /// Adds rhs to self, the shapes must match
+    pub fn try_add(&self, rhs: &CscMatrix<T>) -> Result<CscMatrix<T>, MatrixError> {
+        if self.shape() != rhs.shape() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.shape(),
+                found: rhs.shape(),
+            });
+        }
+        let entries = self
+            .iter()
+            .chain(rhs.iter())
+            .map(|(i, j, v)| (j, i, v))
+            .collect();
+        let (col_offsets, row_indices, values) = compress(self.cols, entries);
+        Ok(CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            col_offsets,
+            row_indices,
+            values,
+        })
+    }
+
+    /// Converts self into coordinate format
+    pub fn to_coo(&self) -> CooMatrix<T> {
+        CooMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self.iter().collect(),
+        }
+    }
+
+    /// Converts self into compressed sparse row format
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        self.to_coo().to_csr()
+    }
+
+    /// Converts self into a dense DMatrix
+    pub fn to_dense(&self) -> DMatrix<T> {
+        self.to_coo().to_dense()
+    }
+}
+
+/// Implements From<&CooMatrix> for CsrMatrix
+impl<T: Scalar> From<&CooMatrix<T>> for CsrMatrix<T> {
+    fn from(coo: &CooMatrix<T>) -> Self {
+        coo.to_csr()
+    }
+}
+
+/// Implements From<&CooMatrix> for CscMatrix
+impl<T: Scalar> From<&CooMatrix<T>> for CscMatrix<T> {
+    fn from(coo: &CooMatrix<T>) -> Self {
+        coo.to_csc()
+    }
+}
+
+/// Implements From<&CsrMatrix> for CscMatrix
+impl<T: Scalar> From<&CsrMatrix<T>> for CscMatrix<T> {
+    fn from(csr: &CsrMatrix<T>) -> Self {
+        csr.to_csc()
+    }
+}
+
+/// Implements From<&CscMatrix> for CsrMatrix
+impl<T: Scalar> From<&CscMatrix<T>> for CsrMatrix<T> {
+    fn from(csc: &CscMatrix<T>) -> Self {
+        csc.to_csr()
+    }
+}
@@ -1,30 +1,67 @@
+mod swizzle;
+
+use super::scalar::{Float, Scalar};
 use super::CoordSystem::*;
 use super::*;
 
+use rand::distributions::uniform::SampleUniform;
 use rand::Rng;
-use std::ops::{Add, Div, Mul, Range, Sub, AddAssign, SubAssign, MulAssign, DivAssign, Rem, RemAssign};
-
-/* TODO: make vectors generic and duplicate each impl block, one for f32, isize */
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Range, Rem, RemAssign, Sub,
+    SubAssign,
+};
+
+/// A Vec2 of f32 components
+pub type Vec2f = Vec2<f32>;
+/// A Vec2 of f64 components
+pub type Vec2d = Vec2<f64>;
+/// A Vec2 of i32 components
+pub type Vec2i = Vec2<i32>;
+/// A Vec3 of f32 components
+pub type Vec3f = Vec3<f32>;
+/// A Vec3 of f64 components
+pub type Vec3d = Vec3<f64>;
+/// A Vec3 of i32 components
+pub type Vec3i = Vec3<i32>;
+
+/* Restricts value to the range entered */
+fn clamp<T: Scalar>(value: T, range: &Range<T>) -> T {
+    if value < range.start {
+        range.start
+    } else if value > range.end {
+        range.end
+    } else {
+        value
+    }
+}
 
 /// A two dimensional mathematical vector
 ///
+/// Generic over any Scalar, defaulting to f32. Operations needing square roots or trigonometry,
+/// such as mag, norm and rotate, are only available when the components are a Float
+///
 /// # Examples
 ///
 /// ```
 /// use miscmath::prelude::*;
 ///
-/// let a = Vec2::default();
+/// let a = Vec2f::default();
+/// let tile = Vec2i::new( &3, &4 ) + Vec2i::new( &1, &-1 );
+/// let b = Vec2d::new( &3.0, &4.0 );
 ///
 /// assert!( ( a.x < 0.00001 ) && ( a.y < 0.00001 ) );
+/// assert_eq!( tile, Vec2i::new( &4, &3 ) );
+/// assert_eq!( tile.dot( &tile ), 25 );
+/// assert_eq!( b.mag(), 5.0 );
 /// ```
 ///
 #[derive(Copy, Clone, Debug)]
-pub struct Vec2 {
-    ///
-    pub x: f32,
-    ///
-    pub y: f32,
-    ///
+pub struct Vec2<T = f32> {
+    /// The x component, or the magnitude when in polar form
+    pub x: T,
+    /// The y component, or the angle when in polar form
+    pub y: T,
+    /// The coordinate system the components are currently expressed in
     coord_system: CoordSystem,
 }
 
@@ -36,16 +73,16 @@ pub struct Vec2 {
 /// ```
 /// use miscmath::prelude::*;
 ///
-/// let a = Vec2::default();
+/// let a = Vec2f::default();
 ///
 /// assert!( ( a.x < 0.00001 ) && ( a.y < 0.00001 ) );
 /// ```
 ///
-impl Default for Vec2 {
+impl<T: Scalar> Default for Vec2<T> {
     fn default() -> Self {
         Self {
-            x: 0.0,
-            y: 0.0,
+            x: T::ZERO,
+            y: T::ZERO,
             coord_system: CARTESIAN,
         }
     }
@@ -57,7 +94,7 @@ impl Default for Vec2 {
 ///
 /// ```
 /// use miscmath::prelude::*;
-/// 
+///
 /// let mut x = Vec2::default();
 /// let y = Vec2::new( &5.0, &7.0);
 /// x += y;
@@ -65,7 +102,7 @@ impl Default for Vec2 {
 /// assert_eq!(x, y);
 /// ```
 ///
-impl Add for Vec2 {
+impl<T: Scalar> Add for Vec2<T> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
@@ -87,8 +124,7 @@ impl Add for Vec2 {
 ///
 /// ```
 ///
-impl AddAssign for Vec2 {
-
+impl<T: Scalar> AddAssign for Vec2<T> {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs;
     }
@@ -104,7 +140,7 @@ impl AddAssign for Vec2 {
 ///
 /// ```
 ///
-impl Sub for Vec2 {
+impl<T: Scalar> Sub for Vec2<T> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -126,7 +162,7 @@ impl Sub for Vec2 {
 ///
 /// ```
 ///
-impl SubAssign for Vec2 {
+impl<T: Scalar> SubAssign for Vec2<T> {
     fn sub_assign(&mut self, rhs: Self) {
         *self = *self - rhs;
     }
@@ -142,7 +178,7 @@ impl SubAssign for Vec2 {
 ///
 /// ```
 ///
-impl Mul for Vec2 {
+impl<T: Scalar> Mul for Vec2<T> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
@@ -164,7 +200,7 @@ impl Mul for Vec2 {
 ///
 /// ```
 ///
-impl MulAssign for Vec2 {
+impl<T: Scalar> MulAssign for Vec2<T> {
     fn mul_assign(&mut self, rhs: Self) {
         *self = *self * rhs;
     }
@@ -180,7 +216,7 @@ impl MulAssign for Vec2 {
 ///
 /// ```
 ///
-impl Div for Vec2 {
+impl<T: Scalar> Div for Vec2<T> {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
@@ -202,7 +238,7 @@ impl Div for Vec2 {
 ///
 /// ```
 ///
-impl DivAssign for Vec2 {
+impl<T: Scalar> DivAssign for Vec2<T> {
     fn div_assign(&mut self, rhs: Self) {
         *self = *self / rhs;
     }
@@ -218,11 +254,11 @@ impl DivAssign for Vec2 {
 ///
 /// ```
 ///
-impl Rem for Vec2 {
+impl<T: Scalar> Rem for Vec2<T> {
     type Output = Self;
 
     fn rem(self, rhs: Self) -> Self::Output {
-        Vec2{
+        Vec2 {
             x: self.x % rhs.x,
             y: self.y % rhs.y,
             coord_system: CARTESIAN,
@@ -240,7 +276,7 @@ impl Rem for Vec2 {
 ///
 /// ```
 ///
-impl RemAssign for Vec2 {
+impl<T: Scalar> RemAssign for Vec2<T> {
     fn rem_assign(&mut self, rhs: Self) {
         *self = *self % rhs;
     }
@@ -256,30 +292,13 @@ impl RemAssign for Vec2 {
 ///
 /// ```
 ///
-impl PartialEq for Vec2 {
-    ///
+impl<T: Scalar> PartialEq for Vec2<T> {
     fn eq(&self, rhs: &Self) -> bool {
-        if (*self - *rhs).x.abs() < 0.00001
-            && (*self - *rhs).y.abs() < 0.00001
-            && self.coord_system == rhs.coord_system
-        {
-            true
-        } else {
-            false
-        }
-    }
-
-    ///
-    fn ne(&self, rhs: &Self) -> bool {
-        if (*self - *rhs).x.abs() > 0.00001 && (*self - *rhs).y.abs() > 0.00001 {
-            true
-        } else {
-            false
-        }
+        self.x.approx_eq(rhs.x) && self.y.approx_eq(rhs.y) && self.coord_system == rhs.coord_system
     }
 }
 
-impl Vec2 {
+impl<T: Scalar> Vec2<T> {
     /*/// Generates a new instance of Vec2 initialized to zero and returns it
     ///
     /// # Examples
@@ -292,7 +311,7 @@ impl Vec2 {
     /// assert!( ( a.x < 0.00001 ) && ( a.y < 0.00001 ) );
     /// ```
     ///
-    pub fn new() -> Vec2 {
+    pub fn new() -> Vec2<T> {
         Vec2 {
             x: 0.0,
             y: 0.0,
@@ -312,35 +331,30 @@ impl Vec2 {
     /// assert!( ( ( a.x - 5.6 ) < 0.00001 ) && ( ( a.y - 7.2 ) < 0.00001 ) );
     /// ```
     ///
-    pub fn new(x: &f32, y: &f32) -> Vec2 {
-        let temp = Vec2 {
+    pub fn new(x: &T, y: &T) -> Vec2<T> {
+        Vec2 {
             x: *x,
             y: *y,
             coord_system: CARTESIAN,
-        };
-        temp
+        }
     }
 
-    /// Generates a new instance of Vec2 initialized to random values in the range entered and returns it
+    /// Adds the components from the rhs Vec2 to the corresponding components of self
     ///
     /// # Examples
     ///
     /// ```
+    ///  
     ///
-    ///
-    ///
+    ///   
     /// ```
     ///
-    pub fn create_random(range_x: &Range<f32>) -> Vec2 {
-        let temp = Vec2 {
-            x: rand::thread_rng().gen_range(range_x.clone()),
-            y: rand::thread_rng().gen_range(range_x.clone()),
-            coord_system: CARTESIAN,
-        };
-        temp
+    pub fn add(&mut self, rhs: &Vec2<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
     }
 
-    /// Generates a new instance of Vec2 initialized to random values in the range entered and returns it
+    ///
     ///
     /// # Examples
     ///
@@ -350,16 +364,12 @@ impl Vec2 {
     ///
     /// ```
     ///
-    pub fn create_random2(range_x: &Range<f32>, range_y: &Range<f32>) -> Vec2 {
-        let temp = Vec2 {
-            x: rand::thread_rng().gen_range(range_x.clone()),
-            y: rand::thread_rng().gen_range(range_y.clone()),
-            coord_system: CARTESIAN,
-        };
-        temp
+    pub fn constrain(&mut self, x_rng: &Range<T>, y_rng: &Range<T>) {
+        self.x = clamp(self.x, x_rng);
+        self.y = clamp(self.y, y_rng);
     }
 
-    /// Generates a new instance of Vec2 based on a entered angle with a magnitude of 1
+    /// Calculates the distance squared between self and the Vec2 passed in
     ///
     /// # Examples
     ///
@@ -369,77 +379,40 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn from_angle(theta: &f32, in_mag: &Option<f32>) -> Vec2 {
-        if let Some(mag) = in_mag {
-            let mut temp = Vec2 {
-                x: *mag,
-                y: *theta,
-                coord_system: POLAR,
-            };
-            temp.swap_system(CARTESIAN);
-            temp
-        } else {
-            let mut temp = Vec2 {
-                x: 1.0,
-                y: *theta,
-                coord_system: POLAR,
-            };
-            temp.swap_system(CARTESIAN);
-            temp
-        }
-    }
-
-    /// Generates a new instance of Vec2 initialized to random values in the range entered and returns it
-    ///
-    /// # Examples
-    ///
-    /// ```
-    ///
-    ///
-    ///
-    /// ```
-    ///
-    pub fn from_rand_angle(range_x: &Range<f32>, mag: &Option<f32>) -> Vec2 {
-        let temp = Vec2::from_angle(&rand::thread_rng().gen_range(range_x.clone()), mag);
-        temp
+    pub fn dist_sq(&self, rhs: &Vec2<T>) -> T {
+        let delta = *self - *rhs;
+        delta.dot(&delta)
     }
 
-    /// Generates a new instance of Vec2 initialized to a magnitude of 1 and a angle of random value in the range entered and returns it
+    /// Prints debug information of self to terminal
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::f32::consts::TAU;
-    /// use miscmath::prelude::*;
-    ///
-    /// let mut a = Vec2::random_unit( &( 0.0..TAU ) );
+    ///  
     ///
-    /// assert!( ( ( a.x - 1.0 ) < 0.00001 ) && ( a.y < TAU ) );
+    ///   
     /// ```
     ///
-    pub fn random_unit(range: &Range<f32>) -> Vec2 {
-        let temp = Vec2::from_angle(&rand::thread_rng().gen_range(range.clone()), &None);
-        temp
+    pub fn dbg(&self) {
+        dbg!(self);
     }
 
-    /// Generates a new instance of Vec2 initialized as a unit vector of angle 0 and returns it
+    /// Calculates the dot product between self and a passed in Vec2
     ///
     /// # Examples
     ///
     /// ```
-    /// use miscmath::prelude::*;
-    ///
-    /// let mut b = Vec2::unit();
+    ///  
     ///
-    /// assert!( ( b.x - 1.0 < 0.00001 ) && ( b.y < 0.00001 ) );
+    ///   
     /// ```
     ///
-    pub fn unit() -> Vec2 {
-        let temp = Vec2::from_angle(&0.0, &Some(1.0));
-        temp
+    pub fn dot(&self, rhs: &Vec2<T>) -> T {
+        (self.x * rhs.x) + (self.y * rhs.y)
     }
 
-    /// Adds the components from the rhs Vec2 to the corresponding components of self
+    /// Divides the components of self by a scalar value rhs
     ///
     /// # Examples
     ///
@@ -449,48 +422,41 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn add(&mut self, rhs: &Vec2) {
-        self.swap_system(CARTESIAN);
-        self.x += rhs.x;
-        self.y += rhs.y;
+    pub fn div(&mut self, rhs: &T) {
+        self.x /= *rhs;
+        self.y /= *rhs;
     }
 
-    /// Calculates the angle between self and a passed in Vec2
+    /// Divides the components of self by a Vec2 rhs
     ///
     /// # Examples
     ///
     /// ```
-    /// use miscmath::prelude::*;
     ///
-    /// let mut a = Vec2::new( &5.6, &7.2 );
-    /// let mut b = Vec2::unit();
     ///
-    /// let angle = a.angle_between( &mut b );
     ///
-    /// assert!( ( angle - 1.4731481877 ) < 0.00001 );
     /// ```
     ///
-    pub fn angle_between(&self, rhs: &Vec2) -> f32 {
-        let dot = self.dot(&rhs);
-        (dot / (self.mag() * rhs.mag())).acos()
+    pub fn div2(&mut self, rhs: &Vec2<T>) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
     }
 
-    ///
+    /// Calculates the magnitude squared of self
     ///
     /// # Examples
     ///
     /// ```
+    ///  
     ///
-    ///
-    ///
+    ///   
     /// ```
     ///
-    pub fn constrain(&mut self, x_rng: &Range<f32>, y_rng: &Range<f32>) {
-        self.x = self.x.clamp(x_rng.start, x_rng.end);
-        self.y = self.y.clamp(y_rng.start, y_rng.end);
+    pub fn mag_sq(&self) -> T {
+        self.dot(self)
     }
 
-    /// Generates a new instance of Vec2 which is perpendicular to the self instance
+    /// Multiplies the components of self by a scalar value rhs
     ///
     /// # Examples
     ///
@@ -500,14 +466,12 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn cross(&mut self) {
-        self.swap_system(CARTESIAN);
-        let x = self.x;
-        self.x = self.y;
-        self.y = -x;
+    pub fn mult(&mut self, rhs: &T) {
+        self.x *= *rhs;
+        self.y *= *rhs;
     }
 
-    /// Calculates the distance between self and the Vec2 passed in
+    /// Multiplies the components of self by a Vec2 rhs
     ///
     /// # Examples
     ///
@@ -517,11 +481,12 @@ impl Vec2 {
     ///
     /// ```
     ///
-    pub fn dist(&self, rhs: &Vec2) -> f32 {
-        ((self.x - rhs.x).powf(2.0)) + ((self.y - rhs.y).powf(2.0)).sqrt()
+    pub fn mult2(&mut self, rhs: &Vec2<T>) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
     }
 
-    /// Calculates the distance squared between self and the Vec2 passed in
+    /// Sets the components of self to the remainder of scalar division by rhs
     ///
     /// # Examples
     ///
@@ -531,11 +496,12 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn dist_sq(&self, rhs: &Vec2) -> f32 {
-        ((self.x - rhs.x).powf(2.0)) + ((self.y - rhs.y).powf(2.0))
+    pub fn rem(&mut self, rhs: &T) {
+        self.x %= *rhs;
+        self.y %= *rhs;
     }
 
-    /// Prints debug information of self to terminal
+    /// Subtracts the components of the rhs Vec2 from the corresponding components of self
     ///
     /// # Examples
     ///
@@ -545,11 +511,12 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn dbg(&self) {
-        dbg!(self);
+    pub fn sub(&mut self, rhs: &Vec2<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
     }
 
-    /// Calculates the dot product between self and a passed in Vec2
+    /// Returns the current coordinate system
     ///
     /// # Examples
     ///
@@ -559,26 +526,31 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn dot(&self, rhs: &Vec2) -> f32 {
-        (self.x * rhs.x) + (self.y * rhs.y)
+    pub fn system(&self) -> CoordSystem {
+        self.coord_system
     }
+}
 
-    /// Divides the components of self by a scalar value rhs
+impl<T: Scalar + SampleUniform> Vec2<T> {
+    /// Generates a new instance of Vec2 initialized to random values in the range entered and returns it
     ///
     /// # Examples
     ///
     /// ```
-    ///  
     ///
-    ///   
+    ///
+    ///
     /// ```
     ///
-    pub fn div(&mut self, rhs: &f32) {
-        self.x /= rhs;
-        self.y /= rhs;
+    pub fn create_random(range_x: &Range<T>) -> Vec2<T> {
+        Vec2 {
+            x: rand::thread_rng().gen_range(range_x.clone()),
+            y: rand::thread_rng().gen_range(range_x.clone()),
+            coord_system: CARTESIAN,
+        }
     }
-    
-    /// Divides the components of self by a Vec2 rhs
+
+    /// Generates a new instance of Vec2 initialized to random values in the range entered and returns it
     ///
     /// # Examples
     ///
@@ -588,47 +560,83 @@ impl Vec2 {
     ///
     /// ```
     ///
-    pub fn div2(&mut self, rhs: &Vec2) {
-        self.x /= rhs.x;
-        self.y /= rhs.y;
+    pub fn create_random2(range_x: &Range<T>, range_y: &Range<T>) -> Vec2<T> {
+        Vec2 {
+            x: rand::thread_rng().gen_range(range_x.clone()),
+            y: rand::thread_rng().gen_range(range_y.clone()),
+            coord_system: CARTESIAN,
+        }
     }
+}
 
-    /// Linearly interpolates between self and a passed in Vec2
+impl<T: Float> Vec2<T> {
+    /// Generates a new instance of Vec2 based on a entered angle with a magnitude of 1
     ///
     /// # Examples
     ///
     /// ```
-    /// use miscmath::prelude::*;
+    ///  
     ///
-    ///	let mut a = Vec2::new( &5.6, &7.2 );
-    /// let mut b = Vec2::unit();
+    ///   
+    /// ```
     ///
-    /// a.lerp( &b, UnitF::new( 0.5 ) );
+    pub fn from_angle(theta: &T, in_mag: &Option<T>) -> Vec2<T> {
+        if let Some(mag) = in_mag {
+            let mut temp = Vec2 {
+                x: *mag,
+                y: *theta,
+                coord_system: POLAR,
+            };
+            temp.swap_system(CARTESIAN);
+            temp
+        } else {
+            let mut temp = Vec2 {
+                x: T::ONE,
+                y: *theta,
+                coord_system: POLAR,
+            };
+            temp.swap_system(CARTESIAN);
+            temp
+        }
+    }
+
+    /// Generates a new instance of Vec2 initialized as a unit vector of angle 0 and returns it
+    ///
+    /// # Examples
     ///
-    /// assert!( ( ( a.x - 3.3 ) < 0.00001 ) && ( ( a.y - 3.6 ) < 0.00001 ) );
     /// ```
+    /// use miscmath::prelude::*;
     ///
-    pub fn lerp(&mut self, rhs: &Vec2, amt: UnitF) {
-        self.swap_system(CARTESIAN);
-        self.x = -(amt.value() - 1.0) * self.x + (amt.value() * rhs.x);
-        self.y = -(amt.value() - 1.0) * self.y + (amt.value() * rhs.y);
+    /// let mut b = Vec2f::unit();
+    ///
+    /// assert!( ( b.x - 1.0 < 0.00001 ) && ( b.y < 0.00001 ) );
+    /// ```
+    ///
+    pub fn unit() -> Vec2<T> {
+        Vec2::from_angle(&T::ZERO, &Some(T::ONE))
     }
 
-    /// Calculates the magnitude of self
+    /// Calculates the angle between self and a passed in Vec2
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use miscmath::prelude::*;
     ///
-    ///   
+    /// let mut a = Vec2::new( &5.6, &7.2 );
+    /// let mut b = Vec2f::unit();
+    ///
+    /// let angle = a.angle_between( &mut b );
+    ///
+    /// assert!( ( angle - 1.4731481877 ) < 0.00001 );
     /// ```
     ///
-    pub fn mag(&self) -> f32 {
-        ((self.x).powf(2.0) + (self.y).powf(2.0)).sqrt()
+    pub fn angle_between(&self, rhs: &Vec2<T>) -> T {
+        let dot = self.dot(rhs);
+        (dot / (self.mag() * rhs.mag())).acos()
     }
 
-    /// Calculates the magnitude squared of self
+    /// Generates a new instance of Vec2 which is perpendicular to the self instance
     ///
     /// # Examples
     ///
@@ -638,43 +646,54 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn mag_sq(&self) -> f32 {
-        (self.x).powf(2.0) + (self.y).powf(2.0)
+    pub fn cross(&mut self) {
+        self.swap_system(CARTESIAN);
+        let x = self.x;
+        self.x = self.y;
+        self.y = -x;
     }
 
-    /// Multiplies the components of self by a scalar value rhs
+    /// Calculates the distance between self and the Vec2 passed in
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use miscmath::prelude::*;
     ///
-    ///   
+    /// let a = Vec2::new( &0.0, &0.0 );
+    /// let b = Vec2::new( &3.0, &4.0 );
+    ///
+    /// assert_eq!( a.dist( &b ), 5.0 );
+    /// assert_eq!( b.dist( &a ), 5.0 );
     /// ```
     ///
-    pub fn mult(&mut self, rhs: &f32) {
-        self.swap_system(CARTESIAN);
-        self.x *= rhs;
-        self.y *= rhs;
+    pub fn dist(&self, rhs: &Vec2<T>) -> T {
+        self.dist_sq(rhs).sqrt()
     }
-    
-    /// Multiplies the components of self by a Vec2 rhs
+
+    /// Linearly interpolates between self and a passed in Vec2
     ///
     /// # Examples
     ///
     /// ```
+    /// use miscmath::prelude::*;
     ///
+    ///    let mut a = Vec2::new( &5.6, &7.2 );
+    /// let mut b = Vec2f::unit();
     ///
+    /// a.lerp( &b, UnitF::new( 0.5 ) );
     ///
+    /// assert!( ( ( a.x - 3.3 ) < 0.00001 ) && ( ( a.y - 3.6 ) < 0.00001 ) );
     /// ```
     ///
-    pub fn mult2(&mut self, rhs: &Vec2) {
+    pub fn lerp(&mut self, rhs: &Vec2<T>, amt: UnitF) {
         self.swap_system(CARTESIAN);
-        self.x *= rhs.x;
-        self.y *= rhs.y;
+        let amt = T::from_f64(f64::from(amt.value()));
+        self.x = -(amt - T::ONE) * self.x + (amt * rhs.x);
+        self.y = -(amt - T::ONE) * self.y + (amt * rhs.y);
     }
 
-    /// Normalizes the magnitude of self to 1, angle is unchanged
+    /// Calculates the magnitude of self
     ///
     /// # Examples
     ///
@@ -684,13 +703,11 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn norm(&mut self) {
-        self.swap_system(POLAR);
-        self.x = 1.0;
-        self.swap_system(CARTESIAN);
+    pub fn mag(&self) -> T {
+        ((self.x).powi(2) + (self.y).powi(2)).sqrt()
     }
 
-    /// Sets the components of self to the remainder of scalar division by rhs
+    /// Normalizes the magnitude of self to 1, angle is unchanged
     ///
     /// # Examples
     ///
@@ -700,10 +717,10 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn rem(&mut self, rhs: &f32) {
+    pub fn norm(&mut self) {
+        self.swap_system(POLAR);
+        self.x = T::ONE;
         self.swap_system(CARTESIAN);
-        self.x %= rhs;
-        self.y %= rhs;
     }
 
     /// Rotates self by the angle entered
@@ -716,33 +733,33 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn rotate(&mut self, theta: f32) {
+    pub fn rotate(&mut self, theta: T) {
         self.swap_system(POLAR);
         self.y += theta;
         self.swap_system(CARTESIAN);
     }
 
-    /// Sets components of vector to values entered
+    /// Sets components of vector to values entered, expressed in the coordinate system entered
+    ///
+    /// Every system other than CARTESIAN takes a magnitude and an angle
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use std::f32::consts::PI;
+    /// use miscmath::prelude::*;
     ///
-    ///   
+    /// let mut a = Vec2::default();
+    /// a.set( &2.0, &( PI / 2.0 ), &CoordSystem::POLAR );
+    ///
+    /// assert_eq!( a, Vec2::new( &0.0, &2.0 ) );
     /// ```
     ///
-    pub fn set(&mut self, input1: &f32, input2: &f32, coord_system: &CoordSystem) {
-        if *coord_system == CARTESIAN {
-            self.swap_system(CARTESIAN);
-            self.x = *input1;
-            self.y = *input2;
-        } else {
-            self.swap_system(POLAR);
-            self.x = *input1;
-            self.y = *input2;
-            self.swap_system(CARTESIAN);
-        }
+    pub fn set(&mut self, input1: &T, input2: &T, coord_system: &CoordSystem) {
+        self.swap_system(*coord_system);
+        self.x = *input1;
+        self.y = *input2;
+        self.swap_system(CARTESIAN);
     }
 
     /// Sets the magnitude of self
@@ -755,7 +772,7 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn set_mag(&mut self, input: &f32) {
+    pub fn set_mag(&mut self, input: &T) {
         self.swap_system(POLAR);
         self.x = *input;
         self.swap_system(CARTESIAN);
@@ -771,13 +788,28 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn set_theta(&mut self, input: &f32) {
+    pub fn set_theta(&mut self, input: &T) {
         self.swap_system(POLAR);
         self.y = *input;
         self.swap_system(CARTESIAN);
     }
 
-    /// Subtracts the components of the rhs Vec2 from the corresponding components of self
+    /* Converts the components of self into the coordinate system entered. Public methods which
+    convert self always swap back to CARTESIAN before returning */
+    pub(crate) fn swap_system(&mut self, new_coord_system: CoordSystem) {
+        if self.coord_system == CARTESIAN && new_coord_system != CARTESIAN {
+            let (x, y) = (self.x, self.y);
+            self.x = x.hypot(y);
+            self.y = y.atan2(x);
+        } else if self.coord_system != CARTESIAN && new_coord_system == CARTESIAN {
+            let (mag, theta) = (self.x, self.y);
+            self.x = mag * theta.cos();
+            self.y = mag * theta.sin();
+        }
+        self.coord_system = new_coord_system;
+    }
+
+    /// Calculates the theta of self
     ///
     /// # Examples
     ///
@@ -787,86 +819,84 @@ impl Vec2 {
     ///   
     /// ```
     ///
-    pub fn sub(&mut self, rhs: &Vec2) {
-        self.swap_system(CARTESIAN);
-        self.x -= rhs.x;
-        self.y -= rhs.y;
+    pub fn theta(&self) -> T {
+        (self.y).atan2(self.x)
     }
+}
 
-    /// Returns the current coordinate system
+impl<T: Float + SampleUniform> Vec2<T> {
+    /// Generates a new instance of Vec2 initialized to random values in the range entered and returns it
     ///
     /// # Examples
     ///
     /// ```
-    ///  
-    ///
-    ///   
-    /// ```
-    ///
-    pub fn system(&self) -> CoordSystem {
-        self.coord_system
-    }
-
-    /// Converts components from cartesian to polar coordinates, and vice versa
     ///
-    /// # Examples
     ///
-    /// ```
-    ///  
     ///
-    ///   
     /// ```
     ///
-    fn swap_system(&mut self, new_coord_system: CoordSystem) {
-        if self.coord_system == CARTESIAN && new_coord_system == POLAR {
-            let x = self.x;
-            self.x = (self.x.powi(2) + self.y.powi(2)).sqrt();
-            self.y = self.y.atan2(x);
-            self.coord_system = new_coord_system;
-        } else if self.coord_system == POLAR && new_coord_system == CARTESIAN {
-            let theta = self.y;
-            self.y = self.x * self.y.sin();
-            self.x *= theta.cos();
-            self.coord_system = new_coord_system;
-        }
+    pub fn from_rand_angle(range_x: &Range<T>, mag: &Option<T>) -> Vec2<T> {
+        Vec2::from_angle(&rand::thread_rng().gen_range(range_x.clone()), mag)
     }
 
-    /// Calculates the theta of self
+    /// Generates a new instance of Vec2 initialized to a magnitude of 1 and a angle of random value in the range entered and returns it
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use std::f32::consts::TAU;
+    /// use miscmath::prelude::*;
     ///
-    ///   
+    /// let mut a = Vec2::random_unit( &( 0.0..TAU ) );
+    ///
+    /// assert!( ( ( a.x - 1.0 ) < 0.00001 ) && ( a.y < TAU ) );
     /// ```
     ///
-    pub fn theta(&self) -> f32 {
-        (self.y).atan2(self.x)
+    pub fn random_unit(range: &Range<T>) -> Vec2<T> {
+        Vec2::from_angle(&rand::thread_rng().gen_range(range.clone()), &None)
     }
 }
 
 /// A three dimensional mathematical vector
 ///
+/// Generic over any Scalar, defaulting to f32. Operations needing square roots or trigonometry
+/// are only available when the components are a Float
+///
+/// The angles follow the physics (ISO 80000-2) spherical convention: theta is the polar angle
+/// measured from the +z axis in [ 0, pi ], and phi is the azimuth measured in the xy plane from the
+/// +x axis towards +y in ( -pi, pi ], so that
+///
+/// x = r sin( theta ) cos( phi ), y = r sin( theta ) sin( phi ), z = r cos( theta )
+///
+/// Swizzle methods such as xy, zyx and xxyz are provided for every combination of components
+///
 /// # Examples
 ///
 /// ```
 /// use miscmath::prelude::*;
 ///
-/// let a = Vec3::default();
+/// let a = Vec3f::default();
+/// let b = Vec3i::new( &1, &2, &3 );
 ///
 /// assert!( ( a.x < 0.00001 ) && ( a.y < 0.00001 ) && ( a.z < 0.00001 ) );
+/// assert_eq!( b.mag_sq(), 14 );
+/// assert_eq!( Vec3d::new( &2.0, &3.0, &6.0 ).mag(), 7.0 );
+///
+/// // Swizzles build vectors of any size from the components of another
+/// assert_eq!( b.zyx(), Vec3::new( &3, &2, &1 ) );
+/// assert_eq!( b.xz(), Vec2::new( &1, &3 ) );
+/// assert_eq!( b.xyzz(), Vec4::new( &1, &2, &3, &3 ) );
 /// ```
 ///
 #[derive(Copy, Clone, Debug)]
-pub struct Vec3 {
-    ///
-    pub x: f32,
-    ///
-    pub y: f32,
-    ///
-    pub z: f32,
-    ///
+pub struct Vec3<T = f32> {
+    /// The x component, or the radius when in polar form
+    pub x: T,
+    /// The y component, or the polar angle theta when in polar form
+    pub y: T,
+    /// The z component, or the azimuth phi when in polar form
+    pub z: T,
+    /// The coordinate system the components are currently expressed in
     coord_system: CoordSystem,
 }
 
@@ -878,17 +908,17 @@ pub struct Vec3 {
 /// ```
 /// use miscmath::prelude::*;
 ///
-/// let a = Vec3::default();
+/// let a = Vec3f::default();
 ///
 /// assert!( ( a.x < 0.000001 ) && ( a.y < 0.000001 ) && ( a.z < 0.00001 ) );
 /// ```
 ///
-impl Default for Vec3 {
+impl<T: Scalar> Default for Vec3<T> {
     fn default() -> Self {
         Self {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
             coord_system: CARTESIAN,
         }
     }
@@ -904,7 +934,7 @@ impl Default for Vec3 {
 ///
 /// ```
 ///
-impl Add for Vec3 {
+impl<T: Scalar> Add for Vec3<T> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
@@ -927,7 +957,7 @@ impl Add for Vec3 {
 ///
 /// ```
 ///
-impl AddAssign for Vec3 {
+impl<T: Scalar> AddAssign for Vec3<T> {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs;
     }
@@ -943,7 +973,7 @@ impl AddAssign for Vec3 {
 ///
 /// ```
 ///
-impl Sub for Vec3 {
+impl<T: Scalar> Sub for Vec3<T> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -966,7 +996,7 @@ impl Sub for Vec3 {
 ///
 /// ```
 ///
-impl SubAssign for Vec3 {
+impl<T: Scalar> SubAssign for Vec3<T> {
     fn sub_assign(&mut self, rhs: Self) {
         *self = *self - rhs;
     }
@@ -982,7 +1012,7 @@ impl SubAssign for Vec3 {
 ///
 /// ```
 ///
-impl Mul for Vec3 {
+impl<T: Scalar> Mul for Vec3<T> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
@@ -1005,7 +1035,7 @@ impl Mul for Vec3 {
 ///
 /// ```
 ///
-impl MulAssign for Vec3 {
+impl<T: Scalar> MulAssign for Vec3<T> {
     fn mul_assign(&mut self, rhs: Self) {
         *self = *self * rhs;
     }
@@ -1021,7 +1051,7 @@ impl MulAssign for Vec3 {
 ///
 /// ```
 ///
-impl Div for Vec3 {
+impl<T: Scalar> Div for Vec3<T> {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
@@ -1040,17 +1070,17 @@ impl Div for Vec3 {
 ///
 /// ```
 /// use miscmath::prelude::*;
-/// 
+///
 /// let mut x = Vec3::new( &100.0, &50.0, &30.0 );
 /// let y = Vec3::new( &2.0, &5.0 , &3.0 );
 /// let z = Vec3::new( &50.0, &10.0, &10.0 );
 ///
 /// x /= y;
-/// 
+///
 /// assert_eq!( x, z );
 /// ```
 ///
-impl DivAssign for Vec3 {
+impl<T: Scalar> DivAssign for Vec3<T> {
     fn div_assign(&mut self, rhs: Self) {
         *self = *self / rhs;
     }
@@ -1066,7 +1096,7 @@ impl DivAssign for Vec3 {
 ///
 /// ```
 ///
-impl Rem for Vec3 {
+impl<T: Scalar> Rem for Vec3<T> {
     type Output = Self;
 
     fn rem(self, rhs: Self) -> Self::Output {
@@ -1089,7 +1119,7 @@ impl Rem for Vec3 {
 ///
 /// ```
 ///
-impl RemAssign for Vec3 {
+impl<T: Scalar> RemAssign for Vec3<T> {
     fn rem_assign(&mut self, rhs: Self) {
         *self = *self % rhs;
     }
@@ -1105,33 +1135,16 @@ impl RemAssign for Vec3 {
 ///
 /// ```
 ///
-impl PartialEq for Vec3 {
-    ///
+impl<T: Scalar> PartialEq for Vec3<T> {
     fn eq(&self, rhs: &Self) -> bool {
-        let temp = *self - *rhs;
-        if temp.x.abs() < 0.00001
-            && temp.y.abs() < 0.00001
-            && temp.z.abs() < 0.00001
+        self.x.approx_eq(rhs.x)
+            && self.y.approx_eq(rhs.y)
+            && self.z.approx_eq(rhs.z)
             && self.coord_system == rhs.coord_system
-        {
-            true
-        } else {
-            false
-        }
-    }
-
-    ///
-    fn ne(&self, rhs: &Self) -> bool {
-        let temp = *self - *rhs;
-        if temp.x.abs() > 0.00001 || temp.y.abs() > 0.00001 || temp.z.abs() > 0.00001 {
-            true
-        } else {
-            false
-        }
     }
 }
 
-impl Vec3 {
+impl<T: Scalar> Vec3<T> {
     /*/// Generates a new instance of Vec3 initialized to zero and returns it
     ///
     /// # Examples
@@ -1144,7 +1157,7 @@ impl Vec3 {
     /// assert!( ( a.x < 0.000001 ) && ( a.y < 0.000001 ) && ( a.z < 0.00001 ) );
     /// ```
     ///
-    pub fn new() -> Vec3 {
+    pub fn new() -> Vec3<T> {
         Vec3 {
             x: 0.0,
             y: 0.0,
@@ -1165,37 +1178,32 @@ impl Vec3 {
     /// assert!( ( ( a.x - 5.6 ) < 0.000001 ) && ( ( a.y - 7.2 ) < 0.000001 ) && ( ( a.z - 6.8 ) < 0.00001 ) );
     /// ```
     ///
-    pub fn new(x: &f32, y: &f32, z: &f32) -> Vec3 {
-        let temp = Vec3 {
+    pub fn new(x: &T, y: &T, z: &T) -> Vec3<T> {
+        Vec3 {
             x: *x,
             y: *y,
             z: *z,
             coord_system: CARTESIAN,
-        };
-        temp
+        }
     }
 
-    /// Generates a new instance of Vec3 initialized to random values in the range entered and returns it
+    /// Adds the components from the rhs Vec3 to the corresponding components of self
     ///
     /// # Examples
     ///
     /// ```
+    ///  
     ///
-    ///
-    ///
+    ///   
     /// ```
     ///
-    pub fn create_random(range_x: &Range<f32>) -> Vec3 {
-        let temp = Vec3 {
-            x: rand::thread_rng().gen_range(range_x.clone()),
-            y: rand::thread_rng().gen_range(range_x.clone()),
-            z: rand::thread_rng().gen_range(range_x.clone()),
-            coord_system: CARTESIAN,
-        };
-        temp
+    pub fn add(&mut self, rhs: &Vec3<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
     }
 
-    /// Generates a new instance of Vec3 initialized to random values in the range entered and returns it
+    ///
     ///
     /// # Examples
     ///
@@ -1205,21 +1213,13 @@ impl Vec3 {
     ///
     /// ```
     ///
-    pub fn create_random3(
-        range_x: &Range<f32>,
-        range_y: &Range<f32>,
-        range_z: &Range<f32>,
-    ) -> Vec3 {
-        let temp = Vec3 {
-            x: rand::thread_rng().gen_range(range_x.clone()),
-            y: rand::thread_rng().gen_range(range_y.clone()),
-            z: rand::thread_rng().gen_range(range_z.clone()),
-            coord_system: CARTESIAN,
-        };
-        temp
+    pub fn constrain(&mut self, x_rng: &Range<T>, y_rng: &Range<T>, z_rng: &Range<T>) {
+        self.x = clamp(self.x, x_rng);
+        self.y = clamp(self.y, y_rng);
+        self.z = clamp(self.z, z_rng);
     }
 
-    /// Generates a new instance of Vec3 based on a entered angles with a magnitude of 1
+    /// Calculates the distance squared between self and the Vec3 passed in
     ///
     /// # Examples
     ///
@@ -1229,87 +1229,40 @@ impl Vec3 {
     ///   
     /// ```
     ///
-    pub fn from_angle(theta: &f32, phi: &f32, in_mag: &Option<f32>) -> Vec3 {
-        if let Some(mag) = in_mag {
-            let mut temp = Vec3 {
-                x: *mag,
-                y: *theta,
-                z: *phi,
-                coord_system: POLAR,
-            };
-            temp.swap_system(CARTESIAN);
-            temp
-        } else {
-            let mut temp = Vec3 {
-                x: 1.0,
-                y: *theta,
-                z: *phi,
-                coord_system: POLAR,
-            };
-            temp.swap_system(CARTESIAN);
-            temp
-        }
-    }
-
-    /// Generates a new instance of Vec3 initialized to random values in the range entered and returns it
-    ///
-    /// # Examples
-    ///
-    /// ```
-    ///
-    ///
-    ///
-    /// ```
-    ///
-    pub fn from_rand_angle(range: &Range<f32>, mag: &Option<f32>) -> Vec3 {
-        let temp = Vec3::from_angle(
-            &rand::thread_rng().gen_range(range.clone()),
-            &rand::thread_rng().gen_range(range.clone()),
-            mag,
-        );
-        temp
+    pub fn dist_sq(&self, rhs: &Vec3<T>) -> T {
+        let delta = *self - *rhs;
+        delta.dot(&delta)
     }
 
-    /// Generates a new instance of Vec3 initialized to a magnitude of 1 and angles of random value in the range entered and returns it
+    /// Prints debug information of self to terminal
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::f32::consts::TAU;
-    /// use miscmath::prelude::*;
-    ///
-    /// let mut a = Vec3::random_unit( &( 0.0..TAU ) );
+    ///  
     ///
-    /// assert!( ( a.x - 1.0 < 0.00001 ) && ( a.y < TAU ) && ( a.z < TAU ) );
+    ///   
     /// ```
     ///
-    pub fn random_unit(range: &Range<f32>) -> Vec3 {
-        let temp = Vec3::from_angle(
-            &rand::thread_rng().gen_range(range.clone()),
-            &rand::thread_rng().gen_range(range.clone()),
-            &None,
-        );
-        temp
+    pub fn dbg(&self) {
+        dbg!(self);
     }
 
-    /// Generates a new instance of Vec3 initialized as a unit vector with angles of 0 and returns it
+    /// Calculates the dot product between self and a passed in Vec3
     ///
     /// # Examples
     ///
     /// ```
-    /// use miscmath::prelude::*;
-    ///
-    /// let mut b = Vec3::unit();
+    ///  
     ///
-    /// assert!( ( b.x < 0.00001 ) && ( b.y < 0.00001 ) && ( b.z - 1.0 < 0.0001 ) );
+    ///   
     /// ```
     ///
-    pub fn unit() -> Vec3 {
-        let temp = Vec3::from_angle(&0.0, &0.0, &Some(1.0));
-        temp
+    pub fn dot(&self, rhs: &Vec3<T>) -> T {
+        (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z)
     }
 
-    /// Adds the components from the rhs Vec3 to the corresponding components of self
+    /// Divides the components of self by a scalar value rhs
     ///
     /// # Examples
     ///
@@ -1319,43 +1272,43 @@ impl Vec3 {
     ///   
     /// ```
     ///
-    pub fn add(&mut self, rhs: &Vec3) {
-        self.swap_system(CARTESIAN);
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
+    pub fn div(&mut self, rhs: &T) {
+        self.x /= *rhs;
+        self.y /= *rhs;
+        self.z /= *rhs;
     }
 
-    /// Calculates the angle between self and a passed in Vec3
+    /// Divides the components of self by a Vec3 rhs
     ///
     /// # Examples
     ///
     /// ```
     ///
+    ///
+    ///
     /// ```
     ///
-    pub fn angle_between(&self, rhs: &Vec3) -> f32 {
-        let dot = self.dot(&rhs);
-        (dot / (self.mag() * rhs.mag())).acos()
+    pub fn div2(&mut self, rhs: &Vec3<T>) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
     }
 
-    ///
+    /// Calculates the magnitude squared of self
     ///
     /// # Examples
     ///
     /// ```
+    ///  
     ///
-    ///
-    ///
+    ///   
     /// ```
     ///
-    pub fn constrain(&mut self, x_rng: &Range<f32>, y_rng: &Range<f32>, z_rng: &Range<f32>) {
-        self.x = self.x.clamp(x_rng.start, x_rng.end);
-        self.y = self.y.clamp(y_rng.start, y_rng.end);
-        self.z = self.z.clamp(z_rng.start, z_rng.end);
+    pub fn mag_sq(&self) -> T {
+        self.dot(self)
     }
 
-    /// Generates a new instance of Vec3 which is perpendicular to the self instance
+    /// Multiplies the components of self by a scalar value rhs
     ///
     /// # Examples
     ///
@@ -1365,22 +1318,13 @@ impl Vec3 {
     ///   
     /// ```
     ///
-    pub fn cross(&mut self, rhs: &Vec3) -> Vec3 {
-        self.swap_system(CARTESIAN);
-
-        let x = (self.y * rhs.z) - (self.z * rhs.y);
-        let y = (self.z * rhs.x) - (self.x * rhs.z);
-        let z = (self.x * rhs.y) - (self.y * rhs.x);
-
-        Vec3 {
-            x,
-            y,
-            z,
-            coord_system: CARTESIAN,
-        }
+    pub fn mult(&mut self, rhs: &T) {
+        self.x *= *rhs;
+        self.y *= *rhs;
+        self.z *= *rhs;
     }
 
-    /// Calculates the distance between self and the Vec3 passed in
+    /// Multiplies the components of self by a Vec3 rhs
     ///
     /// # Examples
     ///
@@ -1390,15 +1334,13 @@ impl Vec3 {
     ///
     /// ```
     ///
-    pub fn dist(&self, rhs: &Vec3) -> f32 {
-        let distance = ((self.x - rhs.x).powf(2.0))
-            + ((self.y - rhs.y).powf(2.0))
-            + ((self.z - rhs.z).powf(2.0));
-
-        distance.sqrt()
+    pub fn mult2(&mut self, rhs: &Vec3<T>) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
     }
 
-    /// Calculates the distance squared between self and the Vec3 passed in
+    /// Sets the components of self to the remainder of scalar division by rhs
     ///
     /// # Examples
     ///
@@ -1408,15 +1350,13 @@ impl Vec3 {
     ///   
     /// ```
     ///
-    pub fn dist_sq(&self, rhs: &Vec3) -> f32 {
-        let distance = ((self.x - rhs.x).powf(2.0))
-            + ((self.y - rhs.y).powf(2.0))
-            + ((self.z - rhs.z).powf(2.0));
-
-        distance
+    pub fn rem(&mut self, rhs: &T) {
+        self.x %= *rhs;
+        self.y %= *rhs;
+        self.z %= *rhs;
     }
 
-    /// Prints debug information of self to terminal
+    /// Subtracts the components of the rhs Vec3 from the corresponding components of self
     ///
     /// # Examples
     ///
@@ -1426,11 +1366,13 @@ impl Vec3 {
     ///   
     /// ```
     ///
-    pub fn dbg(&self) {
-        dbg!(self);
+    pub fn sub(&mut self, rhs: &Vec3<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
     }
 
-    /// Calculates the dot product between self and a passed in Vec3
+    /// Returns the current coordinate system
     ///
     /// # Examples
     ///
@@ -1440,27 +1382,32 @@ impl Vec3 {
     ///   
     /// ```
     ///
-    pub fn dot(&self, rhs: &Vec3) -> f32 {
-        (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z)
+    pub fn system(&self) -> CoordSystem {
+        self.coord_system
     }
+}
 
-    /// Divides the components of self by a scalar value rhs
+impl<T: Scalar + SampleUniform> Vec3<T> {
+    /// Generates a new instance of Vec3 initialized to random values in the range entered and returns it
     ///
     /// # Examples
     ///
     /// ```
-    ///  
     ///
-    ///   
+    ///
+    ///
     /// ```
     ///
-    pub fn div(&mut self, rhs: &f32) {
-        self.x /= rhs;
-        self.y /= rhs;
-        self.z /= rhs;
+    pub fn create_random(range_x: &Range<T>) -> Vec3<T> {
+        Vec3 {
+            x: rand::thread_rng().gen_range(range_x.clone()),
+            y: rand::thread_rng().gen_range(range_x.clone()),
+            z: rand::thread_rng().gen_range(range_x.clone()),
+            coord_system: CARTESIAN,
+        }
     }
-    
-    /// Divides the components of self by a Vec3 rhs
+
+    /// Generates a new instance of Vec3 initialized to random values in the range entered and returns it
     ///
     /// # Examples
     ///
@@ -1470,64 +1417,95 @@ impl Vec3 {
     ///
     /// ```
     ///
-    pub fn div2(&mut self, rhs: &Vec3) {
-        self.x /= rhs.x;
-        self.y /= rhs.y;
-        self.z /= rhs.z;
+    pub fn create_random3(range_x: &Range<T>, range_y: &Range<T>, range_z: &Range<T>) -> Vec3<T> {
+        Vec3 {
+            x: rand::thread_rng().gen_range(range_x.clone()),
+            y: rand::thread_rng().gen_range(range_y.clone()),
+            z: rand::thread_rng().gen_range(range_z.clone()),
+            coord_system: CARTESIAN,
+        }
     }
+}
 
-    /// Linearly interpolates between self and a passed in Vec3
+impl<T: Float> Vec3<T> {
+    /// Generates a new instance of Vec3 from a polar angle theta and an azimuth phi, with a magnitude of 1 unless one is entered
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::f64::consts::PI;
     /// use miscmath::prelude::*;
     ///
-    ///	let mut a = Vec3::new( &5.6, &7.2, &6.8 );
-    /// let mut b = Vec3::default();
+    /// assert_eq!( Vec3d::from_angle( &0.0, &0.0, &None ), Vec3::new( &0.0, &0.0, &1.0 ) );
+    /// assert_eq!( Vec3d::from_angle( &( PI / 2.0 ), &0.0, &Some( 2.0 ) ), Vec3::new( &2.0, &0.0, &0.0 ) );
+    /// assert_eq!( Vec3d::from_angle( &( PI / 2.0 ), &( PI / 2.0 ), &None ), Vec3::new( &0.0, &1.0, &0.0 ) );
     ///
-    /// a.lerp( &b, UnitF::new( 0.5 ) );
+    /// // The angles and magnitude round trip across the whole sphere, away from the poles where phi is undefined
+    /// for i in 1..36 {
+    ///     for j in -35..=36 {
+    ///         let theta = PI * i as f64 / 36.0;
+    ///         let phi = PI * j as f64 / 36.0;
+    ///         let a = Vec3d::from_angle( &theta, &phi, &Some( 3.0 ) );
     ///
-    /// assert!( ( ( a.x - 2.8 ) < 0.00001 ) && ( ( a.y - 3.6 ) < 0.00001 ) && ( a.z - 3.4 ) < 0.00001 );
+    ///         assert!( ( a.theta() - theta ).abs() < 1e-9 );
+    ///         assert!( ( a.phi() - phi ).abs() < 1e-9 );
+    ///         assert!( ( a.mag() - 3.0 ).abs() < 1e-9 );
+    ///     }
+    /// }
     /// ```
     ///
-    pub fn lerp(&mut self, rhs: &Vec3, amt: UnitF) {
-        self.swap_system(CARTESIAN);
-
-        self.x = -(amt.value() - 1.0) * self.x + (amt.value() * rhs.x);
-        self.y = -(amt.value() - 1.0) * self.y + (amt.value() * rhs.y);
-        self.z = -(amt.value() - 1.0) * self.z + (amt.value() * rhs.z);
+    pub fn from_angle(theta: &T, phi: &T, in_mag: &Option<T>) -> Vec3<T> {
+        if let Some(mag) = in_mag {
+            let mut temp = Vec3 {
+                x: *mag,
+                y: *theta,
+                z: *phi,
+                coord_system: POLAR,
+            };
+            temp.swap_system(CARTESIAN);
+            temp
+        } else {
+            let mut temp = Vec3 {
+                x: T::ONE,
+                y: *theta,
+                z: *phi,
+                coord_system: POLAR,
+            };
+            temp.swap_system(CARTESIAN);
+            temp
+        }
     }
 
-    /// Calculates the magnitude of self
+    /// Generates a new instance of Vec3 initialized as a unit vector with angles of 0 and returns it
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use miscmath::prelude::*;
     ///
-    ///   
+    /// let mut b = Vec3f::unit();
+    ///
+    /// assert!( ( b.x < 0.00001 ) && ( b.y < 0.00001 ) && ( b.z - 1.0 < 0.0001 ) );
     /// ```
     ///
-    pub fn mag(&self) -> f32 {
-        (self.x.powf(2.0) + self.y.powf(2.0) + self.z.powf(2.0)).sqrt()
+    pub fn unit() -> Vec3<T> {
+        Vec3::from_angle(&T::ZERO, &T::ZERO, &Some(T::ONE))
     }
 
-    /// Calculates the magnitude squared of self
+    /// Calculates the angle between self and a passed in Vec3
     ///
     /// # Examples
     ///
     /// ```
-    ///  
     ///
-    ///   
     /// ```
     ///
-    pub fn mag_sq(&self) -> f32 {
-        self.x.powf(2.0) + self.y.powf(2.0) + self.z.powf(2.0)
+    pub fn angle_between(&self, rhs: &Vec3<T>) -> T {
+        let dot = self.dot(rhs);
+        (dot / (self.mag() * rhs.mag())).acos()
     }
 
-    /// Multiplies the components of self by a scalar value rhs
+    /// Generates a new instance of Vec3 which is perpendicular to the self instance
     ///
     /// # Examples
     ///
@@ -1537,47 +1515,63 @@ impl Vec3 {
     ///   
     /// ```
     ///
-    pub fn mult(&mut self, rhs: &f32) {
+    pub fn cross(&mut self, rhs: &Vec3<T>) -> Vec3<T> {
         self.swap_system(CARTESIAN);
-        self.x *= rhs;
-        self.y *= rhs;
-        self.z *= rhs;
-    }
-    
-    /// Multiplies the components of self by a Vec3 rhs
-    ///
-    /// # Examples
-    ///
-    /// ```
-    ///
-    ///
+
+        let x = (self.y * rhs.z) - (self.z * rhs.y);
+        let y = (self.z * rhs.x) - (self.x * rhs.z);
+        let z = (self.x * rhs.y) - (self.y * rhs.x);
+
+        Vec3 {
+            x,
+            y,
+            z,
+            coord_system: CARTESIAN,
+        }
+    }
+
+    /// Calculates the distance between self and the Vec3 passed in
+    ///
+    /// # Examples
     ///
     /// ```
     ///
-    pub fn mult2(&mut self, rhs: &Vec3) {
-        self.swap_system(CARTESIAN);
-        self.x *= rhs.x;
-        self.y *= rhs.y;
-        self.z *= rhs.z;
+    ///
+    ///
+    /// ```
+    ///
+    pub fn dist(&self, rhs: &Vec3<T>) -> T {
+        let distance =
+            ((self.x - rhs.x).powi(2)) + ((self.y - rhs.y).powi(2)) + ((self.z - rhs.z).powi(2));
+
+        distance.sqrt()
     }
 
-    /// Normalizes the magnitude of self to 1, angle is unchanged
+    /// Linearly interpolates between self and a passed in Vec3
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use miscmath::prelude::*;
     ///
-    ///   
+    ///    let mut a = Vec3::new( &5.6, &7.2, &6.8 );
+    /// let mut b = Vec3::default();
+    ///
+    /// a.lerp( &b, UnitF::new( 0.5 ) );
+    ///
+    /// assert!( ( ( a.x - 2.8 ) < 0.00001 ) && ( ( a.y - 3.6 ) < 0.00001 ) && ( a.z - 3.4 ) < 0.00001 );
     /// ```
     ///
-    pub fn norm(&mut self) {
-        self.swap_system(POLAR);
-        self.x = 1.0;
+    pub fn lerp(&mut self, rhs: &Vec3<T>, amt: UnitF) {
         self.swap_system(CARTESIAN);
+        let amt = T::from_f64(f64::from(amt.value()));
+
+        self.x = -(amt - T::ONE) * self.x + (amt * rhs.x);
+        self.y = -(amt - T::ONE) * self.y + (amt * rhs.y);
+        self.z = -(amt - T::ONE) * self.z + (amt * rhs.z);
     }
 
-    /// Calculates the theta of self
+    /// Calculates the magnitude of self
     ///
     /// # Examples
     ///
@@ -1587,11 +1581,11 @@ impl Vec3 {
     ///   
     /// ```
     ///
-    pub fn phi(&self) -> f32 {
-        self.x * (self.y).cos()
+    pub fn mag(&self) -> T {
+        (self.x.powi(2) + self.y.powi(2) + self.z.powi(2)).sqrt()
     }
 
-    /// Sets the components of self to the remainder of scalar division by rhs
+    /// Normalizes the magnitude of self to 1, angle is unchanged
     ///
     /// # Examples
     ///
@@ -1601,53 +1595,263 @@ impl Vec3 {
     ///   
     /// ```
     ///
-    pub fn rem(&mut self, rhs: &f32) {
+    pub fn norm(&mut self) {
+        self.swap_system(POLAR);
+        self.x = T::ONE;
         self.swap_system(CARTESIAN);
-        self.x %= rhs;
-        self.y %= rhs;
-        self.z %= rhs;
     }
 
-    /// Rotates self by the angle entered
+    /// Calculates the azimuth phi of self, the angle in the xy plane from the +x axis towards +y, in ( -pi, pi ]
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use std::f32::consts::PI;
+    /// use miscmath::prelude::*;
     ///
-    ///   
+    /// let a = Vec3::new( &0.0, &-2.0, &5.0 );
+    ///
+    /// assert!( ( a.phi() + PI / 2.0 ).abs() < 0.00001 );
+    /// ```
+    ///
+    pub fn phi(&self) -> T {
+        self.y.atan2(self.x)
+    }
+
+    /// Rotates self by adding the angles entered to its polar angle theta and azimuth phi
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use miscmath::prelude::*;
+    ///
+    /// let mut a = Vec3::new( &1.0, &0.0, &0.0 );
+    /// a.rotate( &0.0, &( PI / 2.0 ) );
+    ///
+    /// assert_eq!( a, Vec3::new( &0.0, &1.0, &0.0 ) );
+    ///
+    /// a.rotate( &( -PI / 2.0 ), &0.0 );
+    ///
+    /// assert_eq!( a, Vec3::new( &0.0, &0.0, &1.0 ) );
     /// ```
     ///
-    pub fn rotate(&mut self, theta: &f32, phi: &f32) {
+    pub fn rotate(&mut self, theta: &T, phi: &T) {
         self.swap_system(POLAR);
         self.y += *theta;
         self.z += *phi;
         self.swap_system(CARTESIAN);
     }
 
-    /// Sets components of vector to values entered
+    /// Rotates self by the angle entered around the x axis, counterclockwise when looking down the axis
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use miscmath::prelude::*;
     ///
-    ///   
+    /// let mut a = Vec3::new( &1.0, &1.0, &0.0 );
+    /// a.rotate_x( &FRAC_PI_2 );
+    ///
+    /// assert_eq!( a, Vec3::new( &1.0, &0.0, &1.0 ) );
     /// ```
     ///
-    pub fn set(&mut self, input1: &f32, input2: &f32, input3: &f32, coord_system: &CoordSystem) {
-        if *coord_system == CARTESIAN {
-            self.swap_system(CARTESIAN);
-            self.x = *input1;
-            self.y = *input2;
-            self.z = *input3;
-        } else {
-            self.swap_system(POLAR);
-            self.x = *input1;
-            self.y = *input2;
-            self.z = *input3;
-            self.swap_system(CARTESIAN);
+    pub fn rotate_x(&mut self, angle: &T) {
+        self.swap_system(CARTESIAN);
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let (y, z) = (self.y, self.z);
+
+        self.y = y * cos - z * sin;
+        self.z = y * sin + z * cos;
+    }
+
+    /// Rotates self by the angle entered around the y axis, counterclockwise when looking down the axis
+    pub fn rotate_y(&mut self, angle: &T) {
+        self.swap_system(CARTESIAN);
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let (x, z) = (self.x, self.z);
+
+        self.x = x * cos + z * sin;
+        self.z = z * cos - x * sin;
+    }
+
+    /// Rotates self by the angle entered around the z axis, counterclockwise when looking down the axis
+    pub fn rotate_z(&mut self, angle: &T) {
+        self.swap_system(CARTESIAN);
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let (x, y) = (self.x, self.y);
+
+        self.x = x * cos - y * sin;
+        self.y = x * sin + y * cos;
+    }
+
+    /// Rotates self by the angle entered around an arbitrary axis using Rodrigues' rotation formula
+    ///
+    /// The axis need not be normalized, a zero axis leaves self unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use miscmath::prelude::*;
+    ///
+    /// // A third of a turn around the diagonal cycles the axes
+    /// let mut a = Vec3::new( &1.0, &0.0, &0.0 );
+    /// a.rotate_around( &Vec3::new( &1.0, &1.0, &1.0 ), &( 2.0 * PI / 3.0 ) );
+    ///
+    /// assert_eq!( a, Vec3::new( &0.0, &1.0, &0.0 ) );
+    ///
+    /// let mut b = Vec3::new( &0.3, &-2.0, &0.5 );
+    /// let mut c = b;
+    /// b.rotate_around( &Vec3::new( &0.0, &0.0, &2.0 ), &0.7 );
+    /// c.rotate_z( &0.7 );
+    ///
+    /// assert_eq!( b, c );
+    /// ```
+    ///
+    pub fn rotate_around(&mut self, axis: &Vec3<T>, angle: &T) {
+        self.swap_system(CARTESIAN);
+        let mag = axis.mag();
+        if mag == T::ZERO {
+            return;
         }
+
+        let k = Vec3::new(&(axis.x / mag), &(axis.y / mag), &(axis.z / mag));
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let k_dot_v = k.dot(self);
+        let k_cross_v = Vec3::new(
+            &(k.y * self.z - k.z * self.y),
+            &(k.z * self.x - k.x * self.z),
+            &(k.x * self.y - k.y * self.x),
+        );
+        let along = k_dot_v * (T::ONE - cos);
+
+        self.x = self.x * cos + k_cross_v.x * sin + k.x * along;
+        self.y = self.y * cos + k_cross_v.y * sin + k.y * along;
+        self.z = self.z * cos + k_cross_v.z * sin + k.z * along;
+    }
+
+    /// Generates the projection of self onto rhs, the part of self parallel to rhs
+    ///
+    /// Projecting onto a zero vector gives a zero vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Vec3::new( &2.0, &3.0, &4.0 );
+    /// let floor = Vec3::new( &0.0, &0.0, &5.0 );
+    ///
+    /// assert_eq!( a.project_onto( &floor ), Vec3::new( &0.0, &0.0, &4.0 ) );
+    /// assert_eq!( a.reject_from( &floor ), Vec3::new( &2.0, &3.0, &0.0 ) );
+    /// ```
+    ///
+    pub fn project_onto(&self, rhs: &Vec3<T>) -> Vec3<T> {
+        let rhs_mag_sq = rhs.mag_sq();
+        if rhs_mag_sq == T::ZERO {
+            return Vec3::default();
+        }
+
+        let scale = self.dot(rhs) / rhs_mag_sq;
+        Vec3::new(&(rhs.x * scale), &(rhs.y * scale), &(rhs.z * scale))
+    }
+
+    /// Generates the rejection of self from rhs, the part of self perpendicular to rhs
+    pub fn reject_from(&self, rhs: &Vec3<T>) -> Vec3<T> {
+        *self - self.project_onto(rhs)
+    }
+
+    /// Generates the reflection of self off a surface with the normal entered, which must be normalized
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let ball = Vec3::new( &1.0, &-2.0, &0.5 );
+    /// let ground = Vec3::new( &0.0, &1.0, &0.0 );
+    ///
+    /// assert_eq!( ball.reflect( &ground ), Vec3::new( &1.0, &2.0, &0.5 ) );
+    /// ```
+    ///
+    pub fn reflect(&self, normal: &Vec3<T>) -> Vec3<T> {
+        let scale = (T::ONE + T::ONE) * self.dot(normal);
+        Vec3::new(
+            &(self.x - normal.x * scale),
+            &(self.y - normal.y * scale),
+            &(self.z - normal.z * scale),
+        )
+    }
+
+    /// Generates the refraction of self through a surface with the normal entered, where eta is the
+    /// ratio of the refractive indices, incident over transmitted
+    ///
+    /// Self and the normal must be normalized, with the normal facing against self.
+    /// Returns None on total internal reflection
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let normal = Vec3d::new( &0.0, &1.0, &0.0 );
+    /// let mut ray = Vec3d::new( &1.0, &-1.0, &0.0 );
+    /// ray.norm();
+    ///
+    /// // Air into glass bends towards the normal, following Snell's law
+    /// let bent = ray.refract( &normal, &( 1.0 / 1.5 ) ).unwrap();
+    /// let sin_in = ray.x;
+    /// let sin_out = bent.x;
+    ///
+    /// assert!( ( sin_in - 1.5 * sin_out ).abs() < 1e-12 );
+    /// assert!( ( bent.mag() - 1.0 ).abs() < 1e-12 );
+    ///
+    /// // Glass into air at a shallow angle is totally internally reflected
+    /// assert!( ray.refract( &normal, &1.5 ).is_none() );
+    /// ```
+    ///
+    pub fn refract(&self, normal: &Vec3<T>, eta: &T) -> Option<Vec3<T>> {
+        let cos_in = self.dot(normal);
+        let k = T::ONE - *eta * *eta * (T::ONE - cos_in * cos_in);
+        if k < T::ZERO {
+            return None;
+        }
+
+        let scale = *eta * cos_in + k.sqrt();
+        Some(Vec3::new(
+            &(*eta * self.x - scale * normal.x),
+            &(*eta * self.y - scale * normal.y),
+            &(*eta * self.z - scale * normal.z),
+        ))
+    }
+
+    /// Sets components of vector to values entered, expressed in the coordinate system entered
+    ///
+    /// CYLINDRICAL takes ( rho, phi, z ), while SPHERICAL and POLAR take ( r, theta, phi )
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use miscmath::prelude::*;
+    ///
+    /// let mut a = Vec3::default();
+    ///
+    /// a.set( &2.0, &( PI / 2.0 ), &5.0, &CoordSystem::CYLINDRICAL );
+    /// assert_eq!( a, Vec3::new( &0.0, &2.0, &5.0 ) );
+    ///
+    /// a.set( &2.0, &( PI / 2.0 ), &PI, &CoordSystem::SPHERICAL );
+    /// assert_eq!( a, Vec3::new( &-2.0, &0.0, &0.0 ) );
+    /// ```
+    ///
+    pub fn set(&mut self, input1: &T, input2: &T, input3: &T, coord_system: &CoordSystem) {
+        self.swap_system(*coord_system);
+        self.x = *input1;
+        self.y = *input2;
+        self.z = *input3;
+        self.swap_system(CARTESIAN);
     }
 
     /// Sets the magnitude of self
@@ -1660,116 +1864,661 @@ impl Vec3 {
     ///   
     /// ```
     ///
-    pub fn set_mag(&mut self, input: &f32) {
+    pub fn set_mag(&mut self, input: &T) {
         self.swap_system(POLAR);
         self.x = *input;
         self.swap_system(CARTESIAN);
     }
 
-    /// Sets the theta of self
+    /// Sets the polar angle theta of self, keeping its magnitude and azimuth
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use std::f32::consts::PI;
+    /// use miscmath::prelude::*;
     ///
-    ///   
+    /// let mut a = Vec3::new( &0.0, &3.0, &4.0 );
+    /// a.set_theta( &( PI / 2.0 ) );
+    ///
+    /// assert_eq!( a, Vec3::new( &0.0, &5.0, &0.0 ) );
     /// ```
     ///
-    pub fn set_theta(&mut self, input: &f32) {
+    pub fn set_theta(&mut self, input: &T) {
         self.swap_system(POLAR);
         self.y = *input;
         self.swap_system(CARTESIAN);
     }
 
-    /// Sets the phi of self
+    /// Sets the azimuth phi of self, keeping its magnitude and polar angle
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use std::f32::consts::PI;
+    /// use miscmath::prelude::*;
     ///
-    ///   
+    /// let mut a = Vec3::new( &2.0, &0.0, &1.0 );
+    /// a.set_phi( &PI );
+    ///
+    /// assert_eq!( a, Vec3::new( &-2.0, &0.0, &1.0 ) );
     /// ```
     ///
-    pub fn set_phi(&mut self, input: &f32) {
+    pub fn set_phi(&mut self, input: &T) {
         self.swap_system(POLAR);
         self.z = *input;
         self.swap_system(CARTESIAN);
     }
 
-    /// Subtracts the components of the rhs Vec3 from the corresponding components of self
+    /* Converts the components of self into the coordinate system entered, passing through the
+    cartesian form. Public methods which convert self always swap back to CARTESIAN before returning */
+    pub(crate) fn swap_system(&mut self, new_coord_system: CoordSystem) {
+        if self.coord_system == new_coord_system {
+            return;
+        }
+
+        /* Pass through the cartesian form on the way to the new system */
+        let (x, y, z) = match self.coord_system {
+            CARTESIAN => (self.x, self.y, self.z),
+            CYLINDRICAL => {
+                let (rho, phi, z) = (self.x, self.y, self.z);
+                (rho * phi.cos(), rho * phi.sin(), z)
+            }
+            POLAR | SPHERICAL => {
+                let (r, theta, phi) = (self.x, self.y, self.z);
+                (
+                    r * theta.sin() * phi.cos(),
+                    r * theta.sin() * phi.sin(),
+                    r * theta.cos(),
+                )
+            }
+        };
+
+        (self.x, self.y, self.z) = match new_coord_system {
+            CARTESIAN => (x, y, z),
+            CYLINDRICAL => (x.hypot(y), y.atan2(x), z),
+            POLAR | SPHERICAL => (x.hypot(y).hypot(z), x.hypot(y).atan2(z), y.atan2(x)),
+        };
+        self.coord_system = new_coord_system;
+    }
+
+    /// Calculates the polar angle theta of self, the angle from the +z axis, in [ 0, pi ]
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use miscmath::prelude::*;
     ///
-    ///   
+    /// // Cartesian components round trip through the spherical form, including on the poles
+    /// for x in -3..=3 {
+    ///     for y in -3..=3 {
+    ///         for z in -3..=3 {
+    ///             let a = Vec3d::new( &( x as f64 ), &( y as f64 ), &( z as f64 ) );
+    ///             let b = Vec3d::from_angle( &a.theta(), &a.phi(), &Some( a.mag() ) );
+    ///
+    ///             assert_eq!( a, b );
+    ///             assert!( ( 0.0..=std::f64::consts::PI ).contains( &a.theta() ) );
+    ///         }
+    ///     }
+    /// }
     /// ```
     ///
-    pub fn sub(&mut self, rhs: &Vec3) {
-        self.swap_system(CARTESIAN);
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-        self.z -= rhs.z;
+    pub fn theta(&self) -> T {
+        self.x.hypot(self.y).atan2(self.z)
     }
+}
 
-    /// Returns the current coordinate system
+impl<T: Float + SampleUniform> Vec3<T> {
+    /// Generates a new instance of Vec3 initialized to random values in the range entered and returns it
     ///
     /// # Examples
     ///
     /// ```
-    ///  
     ///
-    ///   
+    ///
+    ///
     /// ```
     ///
-    pub fn system(&self) -> CoordSystem {
-        self.coord_system
+    pub fn from_rand_angle(range: &Range<T>, mag: &Option<T>) -> Vec3<T> {
+        Vec3::from_angle(
+            &rand::thread_rng().gen_range(range.clone()),
+            &rand::thread_rng().gen_range(range.clone()),
+            mag,
+        )
     }
 
-    /// Converts components from cartesian to polar coordinates, and vice versa
+    /// Generates a new instance of Vec3 initialized to a magnitude of 1 and angles of random value in the range entered and returns it
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use std::f32::consts::TAU;
+    /// use miscmath::prelude::*;
+    ///
+    /// let mut a = Vec3::random_unit( &( 0.0..TAU ) );
+    ///
+    /// assert!( ( a.x - 1.0 < 0.00001 ) && ( a.y < TAU ) && ( a.z < TAU ) );
+    /// ```
+    ///
+    pub fn random_unit(range: &Range<T>) -> Vec3<T> {
+        Vec3::from_angle(
+            &rand::thread_rng().gen_range(range.clone()),
+            &rand::thread_rng().gen_range(range.clone()),
+            &None,
+        )
+    }
+}
+
+/// A four dimensional mathematical vector, used for homogeneous coordinates and RGBA colours
+///
+/// Unlike Vec2 and Vec3 it is always expressed in cartesian form
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let point = Vec4::from_vec3( &Vec3::new( &2.0, &4.0, &6.0 ), &2.0 );
+///
+/// assert_eq!( point.truncate(), Vec3::new( &2.0, &4.0, &6.0 ) );
+/// assert_eq!( point.perspective_divide(), Vec3::new( &1.0, &2.0, &3.0 ) );
+/// assert_eq!( Vec4i::new( &1, &2, &3, &4 ).dot( &Vec4i::new( &1, &1, &1, &1 ) ), 10 );
+/// ```
+///
+#[derive(Copy, Clone, Debug)]
+pub struct Vec4<T = f32> {
+    /// The x component
+    pub x: T,
+    /// The y component
+    pub y: T,
+    /// The z component
+    pub z: T,
+    /// The w component
+    pub w: T,
+}
+
+/// A Vec4 of f32 components
+pub type Vec4f = Vec4<f32>;
+/// A Vec4 of f64 components
+pub type Vec4d = Vec4<f64>;
+/// A Vec4 of i32 components
+pub type Vec4i = Vec4<i32>;
+
+/// Implements Default for Vec4
+/// Generates a new instance of Vec4 initialized to zero and returns it
+impl<T: Scalar> Default for Vec4<T> {
+    fn default() -> Self {
+        Self {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
+            w: T::ZERO,
+        }
+    }
+}
+
+/* Implements a component-wise binary operator and its assigning form for Vec4 */
+macro_rules! impl_vec4_op {
+    ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $symbol:tt) => {
+        /// Implements the component-wise operator for Vec4
+        impl<T: Scalar> $op for Vec4<T> {
+            type Output = Self;
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                Self {
+                    x: self.x $symbol rhs.x,
+                    y: self.y $symbol rhs.y,
+                    z: self.z $symbol rhs.z,
+                    w: self.w $symbol rhs.w,
+                }
+            }
+        }
+
+        /// Implements the component-wise assigning operator for Vec4
+        impl<T: Scalar> $op_assign for Vec4<T> {
+            fn $fn_assign(&mut self, rhs: Self) {
+                *self = *self $symbol rhs;
+            }
+        }
+    };
+}
+
+impl_vec4_op!(Add, add, AddAssign, add_assign, +);
+impl_vec4_op!(Sub, sub, SubAssign, sub_assign, -);
+impl_vec4_op!(Mul, mul, MulAssign, mul_assign, *);
+impl_vec4_op!(Div, div, DivAssign, div_assign, /);
+impl_vec4_op!(Rem, rem, RemAssign, rem_assign, %);
+
+/// Implements PartialEq for Vec4
+impl<T: Scalar> PartialEq for Vec4<T> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.x.approx_eq(rhs.x)
+            && self.y.approx_eq(rhs.y)
+            && self.z.approx_eq(rhs.z)
+            && self.w.approx_eq(rhs.w)
+    }
+}
+
+impl<T: Scalar> Vec4<T> {
+    /// Generates a new instance of Vec4 initialized to chosen values and returns it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Vec4::new( &1.0, &0.5, &0.25, &1.0 );
+    ///
+    /// assert_eq!( a * Vec4::new( &2.0, &2.0, &2.0, &1.0 ), Vec4::new( &2.0, &1.0, &0.5, &1.0 ) );
+    /// ```
+    ///
+    pub fn new(x: &T, y: &T, z: &T, w: &T) -> Vec4<T> {
+        Vec4 {
+            x: *x,
+            y: *y,
+            z: *z,
+            w: *w,
+        }
+    }
+
+    /// Generates a new instance of Vec4 from the components of a Vec3 and a w component
+    ///
+    /// A w of 1 makes a point which transforms will translate, a w of 0 makes a direction which they won't
+    pub fn from_vec3(vec: &Vec3<T>, w: &T) -> Vec4<T> {
+        Vec4 {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+            w: *w,
+        }
+    }
+
+    /// Generates a new instance of Vec3 from the x, y and z components of self, dropping w
+    pub fn truncate(&self) -> Vec3<T> {
+        Vec3::new(&self.x, &self.y, &self.z)
+    }
+
+    /// Restricts each component of self to the corresponding range
+    pub fn constrain(
+        &mut self,
+        x_rng: &Range<T>,
+        y_rng: &Range<T>,
+        z_rng: &Range<T>,
+        w_rng: &Range<T>,
+    ) {
+        self.x = clamp(self.x, x_rng);
+        self.y = clamp(self.y, y_rng);
+        self.z = clamp(self.z, z_rng);
+        self.w = clamp(self.w, w_rng);
+    }
+
+    /// Calculates the distance squared between self and the Vec4 passed in
+    pub fn dist_sq(&self, rhs: &Vec4<T>) -> T {
+        let delta = *self - *rhs;
+        delta.dot(&delta)
+    }
+
+    /// Calculates the dot product between self and a passed in Vec4
+    pub fn dot(&self, rhs: &Vec4<T>) -> T {
+        (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z) + (self.w * rhs.w)
+    }
+
+    /// Divides the components of self by a scalar value rhs
+    pub fn div(&mut self, rhs: &T) {
+        self.x /= *rhs;
+        self.y /= *rhs;
+        self.z /= *rhs;
+        self.w /= *rhs;
+    }
+
+    /// Calculates the magnitude squared of self
+    pub fn mag_sq(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Multiplies the components of self by a scalar value rhs
+    pub fn mult(&mut self, rhs: &T) {
+        self.x *= *rhs;
+        self.y *= *rhs;
+        self.z *= *rhs;
+        self.w *= *rhs;
+    }
+}
+
+impl<T: Float> Vec4<T> {
+    /// Calculates the distance between self and the Vec4 passed in
+    pub fn dist(&self, rhs: &Vec4<T>) -> T {
+        self.dist_sq(rhs).sqrt()
+    }
+
+    /// Linearly interpolates between self and a passed in Vec4
+    ///
+    /// # Examples
     ///
-    ///   
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// // Blending two RGBA colours
+    /// let mut red = Vec4::new( &1.0, &0.0, &0.0, &1.0 );
+    /// red.lerp( &Vec4::new( &0.0, &0.0, &1.0, &0.0 ), UnitF::new( 0.25 ) );
+    ///
+    /// assert_eq!( red, Vec4::new( &0.75, &0.0, &0.25, &0.75 ) );
     /// ```
     ///
-    fn swap_system(&mut self, new_coord_system: CoordSystem) {
-        if self.coord_system == CARTESIAN && new_coord_system == POLAR {
-            let x = self.x;
-            let y = self.y;
+    pub fn lerp(&mut self, rhs: &Vec4<T>, amt: UnitF) {
+        let amt = T::from_f64(f64::from(amt.value()));
 
-            self.x = (self.x.powf(2.0) + self.y.powf(2.0)).sqrt();
-            self.y = self.z.atan2(x);
-            self.z = y.atan2(x);
-            self.coord_system = new_coord_system;
-        } else if self.coord_system == POLAR && new_coord_system == CARTESIAN {
-            let mag = self.x;
-            let theta = self.y;
+        self.x = -(amt - T::ONE) * self.x + (amt * rhs.x);
+        self.y = -(amt - T::ONE) * self.y + (amt * rhs.y);
+        self.z = -(amt - T::ONE) * self.z + (amt * rhs.z);
+        self.w = -(amt - T::ONE) * self.w + (amt * rhs.w);
+    }
 
-            self.y = self.x * self.y.sin() * self.z.cos();
-            self.x = self.x * theta.sin() * self.z.sin();
-            self.z = mag * theta.cos();
-            self.coord_system = new_coord_system;
+    /// Calculates the magnitude of self
+    pub fn mag(&self) -> T {
+        self.mag_sq().sqrt()
+    }
+
+    /// Normalizes the magnitude of self to 1, a zero vector is left unchanged
+    pub fn norm(&mut self) {
+        let mag = self.mag();
+        if mag != T::ZERO {
+            self.div(&mag);
         }
     }
 
-    /// Calculates the theta of self
+    /// Generates a new instance of Vec3 by dividing the x, y and z components of self by w
+    ///
+    /// This maps a homogeneous point back to three dimensions. When w is zero the components are returned unchanged
+    pub fn perspective_divide(&self) -> Vec3<T> {
+        if self.w == T::ZERO {
+            self.truncate()
+        } else {
+            Vec3::new(&(self.x / self.w), &(self.y / self.w), &(self.z / self.w))
+        }
+    }
+}
+
+/// A mathematical vector with any number of dimensions, fixed at compile time
+///
+/// Shares the cartesian parts of the Vec2 and Vec3 API, for problems such as neural network weights
+/// or genetic algorithm genomes which live in many dimensions
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a = VecN::from_array( [ 1.0, 2.0, 2.0, 4.0 ] );
+/// let b: VecN<f64, 4> = VecN::default();
+///
+/// assert_eq!( a.mag(), 5.0 );
+/// assert_eq!( a.dist( &b ), 5.0 );
+/// assert_eq!( a[ 3 ], 4.0 );
+/// ```
+///
+#[derive(Copy, Clone, Debug)]
+pub struct VecN<T, const N: usize> {
+    /// The components
+    data: [T; N],
+}
+
+/// Implements Default for VecN
+/// Generates a new instance of VecN initialized to zero and returns it
+impl<T: Scalar, const N: usize> Default for VecN<T, N> {
+    fn default() -> Self {
+        Self { data: [T::ZERO; N] }
+    }
+}
+
+/* Implements a component-wise binary operator and its assigning form for VecN */
+macro_rules! impl_vecn_op {
+    ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $symbol:tt) => {
+        /// Implements the component-wise operator for VecN
+        impl<T: Scalar, const N: usize> $op for VecN<T, N> {
+            type Output = Self;
+
+            fn $fn(mut self, rhs: Self) -> Self::Output {
+                self.$fn_assign(rhs);
+                self
+            }
+        }
+
+        /// Implements the component-wise assigning operator for VecN
+        impl<T: Scalar, const N: usize> $op_assign for VecN<T, N> {
+            fn $fn_assign(&mut self, rhs: Self) {
+                for (lhs, rhs) in self.data.iter_mut().zip(rhs.data) {
+                    *lhs = *lhs $symbol rhs;
+                }
+            }
+        }
+    };
+}
+
+impl_vecn_op!(Add, add, AddAssign, add_assign, +);
+impl_vecn_op!(Sub, sub, SubAssign, sub_assign, -);
+impl_vecn_op!(Mul, mul, MulAssign, mul_assign, *);
+impl_vecn_op!(Div, div, DivAssign, div_assign, /);
+impl_vecn_op!(Rem, rem, RemAssign, rem_assign, %);
+
+/// Implements PartialEq for VecN
+impl<T: Scalar, const N: usize> PartialEq for VecN<T, N> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.data
+            .iter()
+            .zip(&rhs.data)
+            .all(|(a, b)| a.approx_eq(*b))
+    }
+}
+
+/// Implements Index for VecN, returning the component at the index entered
+impl<T, const N: usize> Index<usize> for VecN<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+/// Implements IndexMut for VecN, returning the component at the index entered
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<T: Scalar, const N: usize> VecN<T, N> {
+    /// Generates a new instance of VecN from an array of components and returns it
+    pub fn from_array(data: [T; N]) -> VecN<T, N> {
+        VecN { data }
+    }
+
+    /// Generates a new instance of VecN with every component computed by f from its index and returns it
     ///
     /// # Examples
     ///
     /// ```
-    ///  
+    /// use miscmath::prelude::*;
     ///
-    ///   
+    /// let a: VecN<usize, 5> = VecN::from_fn( | i | i * i );
+    ///
+    /// assert_eq!( a.to_array(), [ 0, 1, 4, 9, 16 ] );
     /// ```
     ///
-    pub fn theta(&self) -> f32 {
-        (self.z).atan2(self.x)
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> VecN<T, N> {
+        VecN {
+            data: std::array::from_fn(f),
+        }
     }
-}
\ No newline at end of file
+
+    /// Returns the number of components of self
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if self has no components
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the components of self as a slice
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the components of self as an array
+    pub fn to_array(&self) -> [T; N] {
+        self.data
+    }
+
+    /// Returns an iterator over the components of self
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    /// Restricts every component of self to the range entered
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let mut a = VecN::from_array( [ -3, 5, 12 ] );
+    /// a.constrain( &( 0..10 ) );
+    ///
+    /// assert_eq!( a, VecN::from_array( [ 0, 5, 10 ] ) );
+    /// ```
+    ///
+    pub fn constrain(&mut self, rng: &Range<T>) {
+        for value in self.data.iter_mut() {
+            *value = clamp(*value, rng);
+        }
+    }
+
+    /// Calculates the distance squared between self and the VecN passed in
+    pub fn dist_sq(&self, rhs: &VecN<T, N>) -> T {
+        let delta = *self - *rhs;
+        delta.dot(&delta)
+    }
+
+    /// Calculates the dot product between self and a passed in VecN
+    pub fn dot(&self, rhs: &VecN<T, N>) -> T {
+        self.data
+            .iter()
+            .zip(&rhs.data)
+            .fold(T::ZERO, |acc, (a, b)| acc + *a * *b)
+    }
+
+    /// Divides the components of self by a scalar value rhs
+    pub fn div(&mut self, rhs: &T) {
+        for value in self.data.iter_mut() {
+            *value /= *rhs;
+        }
+    }
+
+    /// Calculates the magnitude squared of self
+    pub fn mag_sq(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Multiplies the components of self by a scalar value rhs
+    pub fn mult(&mut self, rhs: &T) {
+        for value in self.data.iter_mut() {
+            *value *= *rhs;
+        }
+    }
+}
+
+impl<T: Scalar + SampleUniform, const N: usize> VecN<T, N> {
+    /// Generates a new instance of VecN initialized to random values in the range entered and returns it
+    pub fn create_random(range: &Range<T>) -> VecN<T, N> {
+        let mut rng = rand::thread_rng();
+        VecN::from_fn(|_| rng.gen_range(range.clone()))
+    }
+}
+
+impl<T: Float, const N: usize> VecN<T, N> {
+    /// Calculates the distance between self and the VecN passed in
+    pub fn dist(&self, rhs: &VecN<T, N>) -> T {
+        self.dist_sq(rhs).sqrt()
+    }
+
+    /// Linearly interpolates between self and a passed in VecN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let mut a = VecN::from_array( [ 0.0, 10.0, 4.0 ] );
+    /// a.lerp( &VecN::from_array( [ 10.0, 0.0, 4.0 ] ), UnitF::new( 0.3 ) );
+    ///
+    /// assert_eq!( a, VecN::from_array( [ 3.0, 7.0, 4.0 ] ) );
+    /// ```
+    ///
+    pub fn lerp(&mut self, rhs: &VecN<T, N>, amt: UnitF) {
+        let amt = T::from_f64(f64::from(amt.value()));
+        for (lhs, rhs) in self.data.iter_mut().zip(&rhs.data) {
+            *lhs = -(amt - T::ONE) * *lhs + (amt * *rhs);
+        }
+    }
+
+    /// Calculates the magnitude of self
+    pub fn mag(&self) -> T {
+        self.mag_sq().sqrt()
+    }
+
+    /// Normalizes the magnitude of self to 1, a zero vector is left unchanged
+    pub fn norm(&mut self) {
+        let mag = self.mag();
+        if mag != T::ZERO {
+            self.div(&mag);
+        }
+    }
+}
+
+/// Implements From<[T; N]> for VecN
+impl<T: Scalar, const N: usize> From<[T; N]> for VecN<T, N> {
+    fn from(data: [T; N]) -> Self {
+        VecN { data }
+    }
+}
+
+/// Implements From<Vec2<T>> for VecN<T, 2>
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let a: VecN<f32, 2> = Vec2::new( &1.0, &2.0 ).into();
+///
+/// assert_eq!( Vec2::from( a ), Vec2::new( &1.0, &2.0 ) );
+/// ```
+///
+impl<T: Scalar> From<Vec2<T>> for VecN<T, 2> {
+    fn from(vec: Vec2<T>) -> Self {
+        VecN::from_array([vec.x, vec.y])
+    }
+}
+
+/// Implements From<VecN<T, 2>> for Vec2<T>
+impl<T: Scalar> From<VecN<T, 2>> for Vec2<T> {
+    fn from(vec: VecN<T, 2>) -> Self {
+        Vec2::new(&vec.data[0], &vec.data[1])
+    }
+}
+
+/// Implements From<Vec3<T>> for VecN<T, 3>
+impl<T: Scalar> From<Vec3<T>> for VecN<T, 3> {
+    fn from(vec: Vec3<T>) -> Self {
+        VecN::from_array([vec.x, vec.y, vec.z])
+    }
+}
+
+/// Implements From<VecN<T, 3>> for Vec3<T>
+impl<T: Scalar> From<VecN<T, 3>> for Vec3<T> {
+    fn from(vec: VecN<T, 3>) -> Self {
+        Vec3::new(&vec.data[0], &vec.data[1], &vec.data[2])
+    }
+}
@@ -6,6 +6,6 @@ fn main() {
 
     let y = Vec2::new(&0.5, &7.2);
     dbg!(&y);
- 
-    dbg!(y == y);
+
+    dbg!(x == y);
 }
@@ -0,0 +1,177 @@
+mod fractal;
+mod opensimplex;
+mod perlin;
+mod simplex;
+mod worley;
+
+pub use fractal::{Fractal, FractalMode};
+pub use opensimplex::OpenSimplex2;
+pub use perlin::Perlin;
+pub use simplex::Simplex;
+pub use worley::{DistanceMetric, Worley, WorleyReturn};
+
+use crate::linear::vector::{Vec2, Vec3};
+use crate::linear::CoordSystem::CARTESIAN;
+
+/// The seed used for noise generation when no other seed is wanted
+pub const DEFAULT_NOISE_SEED: usize = 0;
+
+/// The 12 gradients of improved Perlin noise, the midpoints of the edges of a cube
+const GRAD3: [[f32; 3]; 12] = [
+    [1.0, 1.0, 0.0],
+    [-1.0, 1.0, 0.0],
+    [1.0, -1.0, 0.0],
+    [-1.0, -1.0, 0.0],
+    [1.0, 0.0, 1.0],
+    [-1.0, 0.0, 1.0],
+    [1.0, 0.0, -1.0],
+    [-1.0, 0.0, -1.0],
+    [0.0, 1.0, 1.0],
+    [0.0, -1.0, 1.0],
+    [0.0, 1.0, -1.0],
+    [0.0, -1.0, -1.0],
+];
+
+/// A source of coherent noise, sampled in one, two or three dimensions with values in [0,1]
+///
+/// Implemented by every noise generator, so sketches can switch noise type by changing one constructor
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// fn row<N: NoiseFn>( noise: &N ) -> Vec<f32> {
+///     ( 0..8 ).map( |i| noise.get( i as f32 * 0.3 ) ).collect()
+/// }
+///
+/// let perlin = Perlin::new( DEFAULT_NOISE_SEED );
+///
+/// assert_eq!( row( &perlin ), row( &perlin.clone() ) );
+/// assert!( row( &perlin ).iter().all( |v| ( 0.0..=1.0 ).contains( v ) ) );
+/// ```
+///
+pub trait NoiseFn {
+    /// Gets a value between 0 and 1 using one dimensional noise
+    fn noise1(&self, x: f32) -> f32;
+
+    /// Gets a value between 0 and 1 using two dimensional noise
+    fn noise2(&self, x: f32, y: f32) -> f32;
+
+    /// Gets a value between 0 and 1 using three dimensional noise
+    fn noise3(&self, x: f32, y: f32, z: f32) -> f32;
+
+    /// Gets a value between 0 and 1 at a f32, Vec2 or Vec3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let perlin = Perlin::new( DEFAULT_NOISE_SEED );
+    ///
+    /// assert_eq!( perlin.get( 3.7 ), perlin.noise1( 3.7 ) );
+    /// assert_eq!( perlin.get( Vec2::new( &3.7, &-0.2 ) ), perlin.noise2( 3.7, -0.2 ) );
+    /// assert_eq!( perlin.get( Vec3::new( &3.7, &-0.2, &8.0 ) ), perlin.noise3( 3.7, -0.2, 8.0 ) );
+    /// ```
+    ///
+    fn get<T: NoiseInput>(&self, input: T) -> f32 {
+        input.sample(self)
+    }
+}
+
+/* Builds a table of the values 0 to 255, shuffled by a Fisher-Yates shuffle driven by SplitMix64 */
+fn permutation_table(seed: usize) -> [u8; 256] {
+    let mut table = [0; 256];
+    for (i, elem) in table.iter_mut().enumerate() {
+        *elem = i as u8;
+    }
+
+    let mut rng = SplitMix64::new(seed as u64);
+    for i in (1..256).rev() {
+        let j = rng.next_below(i as u64 + 1) as usize;
+        table.swap(i, j);
+    }
+
+    table
+}
+
+/* A small, fast and fully deterministic generator, used so noise depends on nothing but its seed */
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> SplitMix64 {
+        SplitMix64 { state: seed }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /* An unbiased value in 0 to bound - 1, rejecting the values past the last whole multiple of bound */
+    fn next_below(&mut self, bound: u64) -> u64 {
+        let limit = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next();
+            if value < limit {
+                return value % bound;
+            }
+        }
+    }
+}
+
+/* Hashes a lattice point through the permutation table, one axis at a time. Only the low 8 bits of
+each coordinate matter, so the generators step between cells with wrapping arithmetic, which keeps huge
+coordinates from overflowing without changing those bits, and they are masked here before adding to keep
+the sum from overflowing */
+fn hash<const N: usize>(table: &[u8; 256], point: &[i32; N]) -> usize {
+    point
+        .iter()
+        .rev()
+        .fold(0, |h, &c| table[((c & 255) as usize + h) & 255] as usize)
+}
+
+/* Maps raw noise from [-1,1] to [0,1] */
+fn normalize(value: f32) -> f32 {
+    ((value + 1.0) / 2.0).clamp(0.0, 1.0)
+}
+
+/* Linearly interpolates from a to b by t */
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + t * (b - a)
+}
+
+/// A point noise can be sampled at, one, two or three dimensional
+pub trait NoiseInput {
+    /// Samples the noise entered at self, between 0 and 1
+    fn sample<N: NoiseFn + ?Sized>(self, noise: &N) -> f32;
+}
+
+/// Implements NoiseInput for f32, sampling one dimensional noise
+impl NoiseInput for f32 {
+    fn sample<N: NoiseFn + ?Sized>(self, noise: &N) -> f32 {
+        noise.noise1(self)
+    }
+}
+
+/// Implements NoiseInput for Vec2, sampling two dimensional noise
+impl NoiseInput for Vec2 {
+    fn sample<N: NoiseFn + ?Sized>(mut self, noise: &N) -> f32 {
+        self.swap_system(CARTESIAN);
+        noise.noise2(self.x, self.y)
+    }
+}
+
+/// Implements NoiseInput for Vec3, sampling three dimensional noise
+impl NoiseInput for Vec3 {
+    fn sample<N: NoiseFn + ?Sized>(mut self, noise: &N) -> f32 {
+        self.swap_system(CARTESIAN);
+        noise.noise3(self.x, self.y, self.z)
+    }
+}
@@ -0,0 +1,202 @@
+use super::NoiseFn;
+
+/// The ways Fractal combines its octaves
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FractalMode {
+    /// Fractional brownian motion, the plain weighted sum of the octaves, like p5.js noise()
+    Fbm,
+    /// Musgrave's ridged multifractal, sharp ridges where the noise crosses its midpoint,
+    /// with each octave weighted by the one before so valleys stay smooth
+    Ridged,
+    /// The ridged signal turned upside down, rounded lumps separated by sharp creases
+    Billow,
+    /// Perlin's turbulence, the weighted sum of the distance of each octave from its midpoint
+    Turbulence,
+}
+
+/// Fractal noise, layering octaves of a base noise at rising frequency and falling amplitude
+///
+/// Each octave samples the base noise at lacunarity times the frequency of the one before, with
+/// persistence ( also called gain ) times its amplitude. The result is normalized to [0,1]
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let mut fractal = Fractal::new( Perlin::new( DEFAULT_NOISE_SEED ) );
+/// fractal.detail( &6, &0.4 );
+/// fractal.set_mode( &FractalMode::Ridged );
+///
+/// assert_eq!( fractal.octaves(), 6 );
+/// assert_eq!( fractal.persistence(), 0.4 );
+///
+/// for i in 0..100 {
+///     for j in 0..100 {
+///         let value = fractal.noise2( i as f32 * 0.05, j as f32 * 0.05 );
+///
+///         assert!( ( 0.0..=1.0 ).contains( &value ) );
+///     }
+/// }
+///
+/// // A single Fbm octave is just the base noise
+/// let perlin = Perlin::new( 3 );
+/// let mut single = Fractal::new( perlin.clone() );
+/// single.set_octaves( &1 );
+///
+/// assert_eq!( single.noise3( 0.2, 0.4, 0.6 ), perlin.noise3( 0.2, 0.4, 0.6 ) );
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Fractal<N: NoiseFn> {
+    /// The noise each octave samples
+    source: N,
+    /// The number of octaves summed, at least 1
+    octaves: usize,
+    /// The factor the frequency grows by each octave
+    lacunarity: f32,
+    /// The factor the amplitude shrinks by each octave
+    persistence: f32,
+    /// How the octaves are combined
+    mode: FractalMode,
+}
+
+impl<N: NoiseFn> Fractal<N> {
+    /// Generates a new instance of Fractal over the source entered and returns it
+    ///
+    /// Uses the p5.js defaults of 4 octaves and a falloff of 0.5, with a lacunarity of 2 and Fbm
+    pub fn new(source: N) -> Fractal<N> {
+        Fractal {
+            source,
+            octaves: 4,
+            lacunarity: 2.0,
+            persistence: 0.5,
+            mode: FractalMode::Fbm,
+        }
+    }
+
+    /// Sets the number of octaves and the falloff of each, like p5.js noiseDetail()
+    pub fn detail(&mut self, lod: &usize, falloff: &f32) {
+        self.set_octaves(lod);
+        self.set_persistence(falloff);
+    }
+
+    /// Returns the noise each octave samples
+    pub fn source(&self) -> &N {
+        &self.source
+    }
+
+    /// Returns the number of octaves summed
+    pub fn octaves(&self) -> usize {
+        self.octaves
+    }
+
+    /// Sets the number of octaves summed, values below 1 are raised to 1
+    pub fn set_octaves(&mut self, octaves: &usize) {
+        self.octaves = (*octaves).max(1);
+    }
+
+    /// Returns the factor the frequency grows by each octave
+    pub fn lacunarity(&self) -> f32 {
+        self.lacunarity
+    }
+
+    /// Sets the factor the frequency grows by each octave
+    pub fn set_lacunarity(&mut self, lacunarity: &f32) {
+        self.lacunarity = *lacunarity;
+    }
+
+    /// Returns the factor the amplitude shrinks by each octave
+    pub fn persistence(&self) -> f32 {
+        self.persistence
+    }
+
+    /// Sets the factor the amplitude shrinks by each octave, also called gain
+    pub fn set_persistence(&mut self, persistence: &f32) {
+        self.persistence = *persistence;
+    }
+
+    /// Returns how the octaves are combined
+    pub fn mode(&self) -> FractalMode {
+        self.mode
+    }
+
+    /// Sets how the octaves are combined
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let mut fractal = Fractal::new( Perlin::new( DEFAULT_NOISE_SEED ) );
+    ///
+    /// for mode in [ FractalMode::Fbm, FractalMode::Ridged, FractalMode::Billow, FractalMode::Turbulence ] {
+    ///     fractal.set_mode( &mode );
+    ///
+    ///     for i in 0..1000 {
+    ///         let x = i as f32 * 0.013;
+    ///         let value = fractal.noise1( x );
+    ///
+    ///         assert!( ( 0.0..=1.0 ).contains( &value ) );
+    ///         assert!( ( value - fractal.noise1( x + 0.0001 ) ).abs() < 0.01 );
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub fn set_mode(&mut self, mode: &FractalMode) {
+        self.mode = *mode;
+    }
+
+    /* Combines the octaves, sample takes a frequency and returns the source noise at the point scaled by it */
+    fn combine<F: Fn(f32) -> f32>(&self, sample: F) -> f32 {
+        let mut frequency = 1.0;
+        let mut amplitude = 1.0;
+        let mut total = 0.0;
+        let mut max = 0.0;
+        /* Only used by Ridged, how much the previous octave lets through */
+        let mut weight = 1.0;
+
+        for _ in 0..self.octaves {
+            let noise = sample(frequency);
+            /* The source noise mapped to [-1,1] */
+            let signed = noise * 2.0 - 1.0;
+
+            let value = match self.mode {
+                FractalMode::Fbm => noise,
+                FractalMode::Ridged => {
+                    let ridge = (1.0 - signed.abs()).powi(2) * weight;
+                    weight = (ridge * 2.0).clamp(0.0, 1.0);
+                    ridge
+                }
+                FractalMode::Billow => 1.0 - (1.0 - signed.abs()).powi(2),
+                FractalMode::Turbulence => signed.abs(),
+            };
+
+            total += value * amplitude;
+            max += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.persistence;
+        }
+
+        if max == 0.0 {
+            0.0
+        } else {
+            (total / max).clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// Implements NoiseFn for Fractal, so fractals can be sampled like any other noise
+impl<N: NoiseFn> NoiseFn for Fractal<N> {
+    fn noise1(&self, x: f32) -> f32 {
+        self.combine(|f| self.source.noise1(x * f))
+    }
+
+    fn noise2(&self, x: f32, y: f32) -> f32 {
+        self.combine(|f| self.source.noise2(x * f, y * f))
+    }
+
+    fn noise3(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.combine(|f| self.source.noise3(x * f, y * f, z * f))
+    }
+}
@@ -0,0 +1,218 @@
+use super::simplex::{F2, G2};
+use super::{hash, normalize, permutation_table, NoiseFn, GRAD3};
+use crate::random;
+
+use std::f32::consts::FRAC_1_SQRT_2;
+
+/// 12 unit gradients evenly spread around the circle, turned 15 degrees off the axes
+const GRAD2: [[f32; 2]; 12] = [
+    [0.965_925_8, 0.258_819_04],
+    [FRAC_1_SQRT_2, FRAC_1_SQRT_2],
+    [0.258_819_04, 0.965_925_8],
+    [-0.258_819_04, 0.965_925_8],
+    [-FRAC_1_SQRT_2, FRAC_1_SQRT_2],
+    [-0.965_925_8, 0.258_819_04],
+    [-0.965_925_8, -0.258_819_04],
+    [-FRAC_1_SQRT_2, -FRAC_1_SQRT_2],
+    [-0.258_819_04, -0.965_925_8],
+    [0.258_819_04, -0.965_925_8],
+    [FRAC_1_SQRT_2, -FRAC_1_SQRT_2],
+    [0.965_925_8, -0.258_819_04],
+];
+
+/// OpenSimplex2 Noise struct
+///
+/// Generates OpenSimplex2 noise, which sums wider, smoother kernels than Simplex. In two
+/// dimensions it uses the simplex lattice, in three the body centred cubic lattice turned so
+/// its main diagonal runs along z, which keeps xy slices free of grid artifacts when z is time.
+/// Values are normalized to [0,1] and seeded the same way as Perlin
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// // Switching the type of noise only changes the constructor
+/// let noise = OpenSimplex2::new( DEFAULT_NOISE_SEED );
+///
+/// for t in 0..10 {
+///     for i in 0..50 {
+///         let ( x, y, z ) = ( i as f32 * 0.09, 1.3, t as f32 * 0.1 );
+///         let value = noise.noise3( x, y, z );
+///
+///         assert!( ( 0.0..=1.0 ).contains( &value ) );
+///         assert!( ( value - noise.noise3( x + 0.001, y, z ) ).abs() < 0.01 );
+///         assert_eq!( value, noise.get( Vec3::new( &x, &y, &z ) ) );
+///     }
+/// }
+///
+/// // Coordinates past the range of i32 still give a value
+/// assert!( ( 0.0..=1.0 ).contains( &noise.noise3( 3e9, -3e9, 1e38 ) ) );
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct OpenSimplex2 {
+    /// The seed the noise was generated from
+    pub seed: usize,
+    /// Shuffled table of the values 0 to 255
+    permutation_table: [u8; 256],
+}
+
+impl OpenSimplex2 {
+    /// Initializes and returns a new OpenSimplex2 object
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = OpenSimplex2::new( DEFAULT_NOISE_SEED );
+    /// let b = OpenSimplex2::new( 42 );
+    ///
+    /// assert_eq!( a.noise2( 0.3, 1.7 ), OpenSimplex2::new( DEFAULT_NOISE_SEED ).noise2( 0.3, 1.7 ) );
+    /// assert_ne!( a.noise2( 0.3, 1.7 ), b.noise2( 0.3, 1.7 ) );
+    /// ```
+    ///
+    pub fn new(seed: usize) -> OpenSimplex2 {
+        OpenSimplex2 {
+            seed,
+            permutation_table: permutation_table(seed),
+        }
+    }
+
+    /// Initializes and returns a new OpenSimplex2 object with a seed picked at random
+    pub fn with_random_seed() -> OpenSimplex2 {
+        OpenSimplex2::new(random(0..usize::MAX))
+    }
+
+    /// Rebuilds the permutation table from a new seed, as if self was created with OpenSimplex2::new( seed )
+    pub fn reseed(&mut self, seed: usize) {
+        self.seed = seed;
+        self.permutation_table = permutation_table(seed);
+    }
+}
+
+/// Implements NoiseFn for OpenSimplex2
+impl NoiseFn for OpenSimplex2 {
+    /// Gets a value between 0 and 1 using OpenSimplex2 noise along the line y = 0 of the two dimensional noise
+    fn noise1(&self, x: f32) -> f32 {
+        self.noise2(x, 0.0)
+    }
+
+    /// Gets a value between 0 and 1 using two dimensional OpenSimplex2 noise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let noise = OpenSimplex2::new( DEFAULT_NOISE_SEED );
+    ///
+    /// for i in 0..200 {
+    ///     for j in 0..200 {
+    ///         let ( x, y ) = ( i as f32 * 0.037, j as f32 * 0.041 );
+    ///         let value = noise.noise2( x, y );
+    ///
+    ///         assert!( ( 0.0..=1.0 ).contains( &value ) );
+    ///         assert!( ( value - noise.noise2( x + 0.001, y ) ).abs() < 0.01 );
+    ///         assert!( ( value - noise.noise2( x, y + 0.001 ) ).abs() < 0.01 );
+    ///     }
+    /// }
+    /// ```
+    ///
+    fn noise2(&self, x: f32, y: f32) -> f32 {
+        /* Skew the point to find the rhombus of two triangles it is in */
+        let skewed = (x + y) * F2;
+        let (xb, yb) = ((x + skewed).floor(), (y + skewed).floor());
+
+        /* The kernels reach one edge length, so the ring of points around the rhombus covers them all */
+        let mut total = 0.0;
+        for di in -1..=2 {
+            for dj in -1..=2 {
+                let (i, j) = (xb + di as f32, yb + dj as f32);
+                let unskewed = (i + j) * G2;
+                let (dx, dy) = (x - (i - unskewed), y - (j - unskewed));
+
+                let falloff = RADIUS_SQ_2 - dx * dx - dy * dy;
+                if falloff > 0.0 {
+                    let cell = [(xb as i32).wrapping_add(di), (yb as i32).wrapping_add(dj)];
+                    let g = GRAD2[hash(&self.permutation_table, &cell) % 12];
+                    let falloff = falloff * falloff;
+                    total += falloff * falloff * (g[0] * dx + g[1] * dy);
+                }
+            }
+        }
+
+        normalize(SCALE2 * total)
+    }
+
+    /// Gets a value between 0 and 1 using three dimensional OpenSimplex2 noise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let noise = OpenSimplex2::new( DEFAULT_NOISE_SEED );
+    ///
+    /// for i in 0..40 {
+    ///     for j in 0..40 {
+    ///         for k in 0..20 {
+    ///             let ( x, y, z ) = ( i as f32 * 0.13, j as f32 * 0.17, k as f32 * 0.11 );
+    ///             let value = noise.noise3( x, y, z );
+    ///
+    ///             assert!( ( 0.0..=1.0 ).contains( &value ) );
+    ///             assert!( ( value - noise.noise3( x, y + 0.001, z ) ).abs() < 0.01 );
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    fn noise3(&self, x: f32, y: f32, z: f32) -> f32 {
+        /* Turn the lattice so its main diagonal runs along z */
+        let xy = x + y;
+        let s = xy * -0.211_324_87;
+        let zz = z * 0.577_350_26;
+        let point = [x + s + zz, y + s + zz, xy * -0.577_350_26 + zz];
+
+        /* The lattice is two cubic grids offset by half a cell, the kernels reach less than a cell
+        so only the corners of the cell around the point in each grid can contribute */
+        let mut total = 0.0;
+        for lattice in 0..2 {
+            let shift = lattice as f32 * 0.5;
+            let base = point.map(|p| (p - shift).floor());
+
+            for corner in 0..8 {
+                let step = [corner & 1, (corner >> 1) & 1, corner >> 2];
+                let d = [
+                    point[0] - (base[0] + step[0] as f32 + shift),
+                    point[1] - (base[1] + step[1] as f32 + shift),
+                    point[2] - (base[2] + step[2] as f32 + shift),
+                ];
+
+                let falloff = RADIUS_SQ_3 - d[0] * d[0] - d[1] * d[1] - d[2] * d[2];
+                if falloff > 0.0 {
+                    /* Both grids hash as one in doubled coordinates */
+                    let mut doubled = [0; 3];
+                    for k in 0..3 {
+                        let cell = (base[k] as i32).wrapping_add(step[k]);
+                        doubled[k] = cell.wrapping_mul(2).wrapping_add(lattice);
+                    }
+                    let g = GRAD3[hash(&self.permutation_table, &doubled) % 12];
+                    let falloff = falloff * falloff;
+                    total += falloff * falloff * (g[0] * d[0] + g[1] * d[1] + g[2] * d[2]);
+                }
+            }
+        }
+
+        normalize(SCALE3 * total)
+    }
+}
+
+/* The squared radius each lattice point reaches */
+const RADIUS_SQ_2: f32 = 2.0 / 3.0;
+const RADIUS_SQ_3: f32 = 0.75;
+
+/* Scale the sum of the kernels to about [-1,1], measured from dense sampling */
+const SCALE2: f32 = 18.0;
+const SCALE3: f32 = 9.0;
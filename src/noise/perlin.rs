@@ -0,0 +1,287 @@
+use super::{lerp, normalize, permutation_table, NoiseFn, GRAD3};
+use crate::random;
+
+/// The 8 gradients used in two dimensions, the axes and diagonals
+const GRAD2: [[f32; 2]; 8] = [
+    [1.0, 1.0],
+    [-1.0, 1.0],
+    [1.0, -1.0],
+    [-1.0, -1.0],
+    [1.0, 0.0],
+    [-1.0, 0.0],
+    [0.0, 1.0],
+    [0.0, -1.0],
+];
+
+/// Perlin Noise struct
+///
+/// Generates Ken Perlin's improved noise in one, two or three dimensions. Values are normalized
+/// to [0,1] like p5.js noise(), and vary smoothly with the input
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let perlin = Perlin::new( DEFAULT_NOISE_SEED );
+///
+/// let a = perlin.noise2( 0.5, 1.25 );
+/// let b = perlin.noise2( 0.5001, 1.25 );
+///
+/// assert!( ( 0.0..=1.0 ).contains( &a ) );
+/// assert!( ( a - b ).abs() < 0.001 );
+///
+/// // The same input always gives the same value
+/// assert_eq!( a, perlin.noise2( 0.5, 1.25 ) );
+/// assert_eq!( a, perlin.get( Vec2::new( &0.5, &1.25 ) ) );
+/// assert_eq!( a, perlin.clone().noise2( 0.5, 1.25 ) );
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Perlin {
+    /// The seed the noise was generated from
+    pub seed: usize,
+    /// Shuffled table of the values 0 to 255
+    permutation_table: [u8; 256],
+}
+
+impl Perlin {
+    /// Initializes and returns a new Perlin object
+    ///
+    /// The permutation table is shuffled by a generator seeded with seed, so the same seed always
+    /// gives the same noise, on every run and every platform
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Perlin::new( DEFAULT_NOISE_SEED );
+    /// let b = Perlin::new( DEFAULT_NOISE_SEED );
+    /// let c = Perlin::new( 42 );
+    ///
+    /// assert_eq!( a.noise3( 0.3, 1.7, 2.9 ), b.noise3( 0.3, 1.7, 2.9 ) );
+    /// assert_ne!( a.noise3( 0.3, 1.7, 2.9 ), c.noise3( 0.3, 1.7, 2.9 ) );
+    ///
+    /// // Golden values, a change here means every seeded sketch would change too
+    /// assert!( ( a.noise1( 0.3 ) - 0.36308002 ).abs() < 0.000001 );
+    /// assert!( ( a.noise2( 0.5, 1.25 ) - 0.44396973 ).abs() < 0.000001 );
+    /// assert!( ( a.noise3( 0.3, 1.7, 2.9 ) - 0.62228173 ).abs() < 0.000001 );
+    /// assert!( ( Perlin::new( 1234567 ).noise2( 10.1, -3.3 ) - 0.6777059 ).abs() < 0.000001 );
+    /// ```
+    ///
+    pub fn new(seed: usize) -> Perlin {
+        let mut perlin = Perlin {
+            seed,
+            permutation_table: [0; 256],
+        };
+        perlin.reseed(seed);
+
+        perlin
+    }
+
+    /// Initializes and returns a new Perlin object with a seed picked at random
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Perlin::with_random_seed();
+    /// let b = Perlin::new( a.seed );
+    ///
+    /// assert_eq!( a.noise2( 4.2, 0.7 ), b.noise2( 4.2, 0.7 ) );
+    /// ```
+    ///
+    pub fn with_random_seed() -> Perlin {
+        Perlin::new(random(0..usize::MAX))
+    }
+
+    /// Rebuilds the permutation table from a new seed, as if self was created with Perlin::new( seed )
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let mut a = Perlin::new( 7 );
+    /// let before = a.noise1( 2.5 );
+    ///
+    /// a.reseed( 8 );
+    /// assert_eq!( a.seed, 8 );
+    /// assert_eq!( a.noise1( 2.5 ), Perlin::new( 8 ).noise1( 2.5 ) );
+    ///
+    /// a.reseed( 7 );
+    /// assert_eq!( a.noise1( 2.5 ), before );
+    /// ```
+    ///
+    pub fn reseed(&mut self, seed: usize) {
+        self.seed = seed;
+        self.permutation_table = permutation_table(seed);
+    }
+}
+
+/// Implements NoiseFn for Perlin
+impl NoiseFn for Perlin {
+    /// Gets a value between 0 and 1 using one dimensional perlin noise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let perlin = Perlin::new( DEFAULT_NOISE_SEED );
+    ///
+    /// // Sweeping the input never leaves [0,1] or jumps between nearby samples
+    /// let mut last = perlin.noise1( 0.0 );
+    /// for i in 1..10000 {
+    ///     let value = perlin.noise1( i as f32 * 0.001 );
+    ///
+    ///     assert!( ( 0.0..=1.0 ).contains( &value ) );
+    ///     assert!( ( value - last ).abs() < 0.01 );
+    ///     last = value;
+    /// }
+    ///
+    /// // Lattice points are always the midpoint
+    /// assert_eq!( perlin.noise1( 4.0 ), 0.5 );
+    /// ```
+    ///
+    fn noise1(&self, x: f32) -> f32 {
+        let (xi, xf) = Perlin::split(x);
+        let u = Perlin::fade(xf);
+
+        let g0 = self.grad1(self.hash(xi), xf);
+        let g1 = self.grad1(self.hash(xi + 1), xf - 1.0);
+
+        /* A gradient of +-1 reaches at most 0.5, so scale to [-1,1] */
+        normalize(2.0 * lerp(g0, g1, u))
+    }
+
+    /// Gets a value between 0 and 1 using two dimensional perlin noise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let perlin = Perlin::new( DEFAULT_NOISE_SEED );
+    ///
+    /// let mut min: f32 = 1.0;
+    /// let mut max: f32 = 0.0;
+    /// for i in 0..200 {
+    ///     for j in 0..200 {
+    ///         let ( x, y ) = ( i as f32 * 0.037, j as f32 * 0.041 );
+    ///         let value = perlin.noise2( x, y );
+    ///
+    ///         assert!( ( 0.0..=1.0 ).contains( &value ) );
+    ///         assert!( ( value - perlin.noise2( x + 0.001, y ) ).abs() < 0.01 );
+    ///         assert!( ( value - perlin.noise2( x, y + 0.001 ) ).abs() < 0.01 );
+    ///
+    ///         min = min.min( value );
+    ///         max = max.max( value );
+    ///     }
+    /// }
+    ///
+    /// // The values spread across the range rather than sitting near 0.5
+    /// assert!( min < 0.3 && max > 0.7 );
+    /// ```
+    ///
+    fn noise2(&self, x: f32, y: f32) -> f32 {
+        let (xi, xf) = Perlin::split(x);
+        let (yi, yf) = Perlin::split(y);
+        let (u, v) = (Perlin::fade(xf), Perlin::fade(yf));
+
+        let corner = |dx: i32, dy: i32| {
+            let hash = self.hash(self.hash(xi + dx) as i32 + yi + dy);
+            let g = GRAD2[hash & 7];
+            g[0] * (xf - dx as f32) + g[1] * (yf - dy as f32)
+        };
+
+        let bottom = lerp(corner(0, 0), corner(1, 0), u);
+        let top = lerp(corner(0, 1), corner(1, 1), u);
+
+        normalize(lerp(bottom, top, v))
+    }
+
+    /// Gets a value between 0 and 1 using three dimensional perlin noise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let perlin = Perlin::new( DEFAULT_NOISE_SEED );
+    ///
+    /// for i in 0..50 {
+    ///     for j in 0..50 {
+    ///         for k in 0..20 {
+    ///             let ( x, y, z ) = ( i as f32 * 0.13, j as f32 * 0.17, k as f32 * 0.11 );
+    ///             let value = perlin.noise3( x, y, z );
+    ///
+    ///             assert!( ( 0.0..=1.0 ).contains( &value ) );
+    ///             assert!( ( value - perlin.noise3( x, y, z + 0.001 ) ).abs() < 0.01 );
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // Noise is continuous across the lattice cells too
+    /// assert!( ( perlin.noise3( 0.9999, 2.0, 5.5 ) - perlin.noise3( 1.0001, 2.0, 5.5 ) ).abs() < 0.01 );
+    ///
+    /// // Coordinates past the range of i32 still give a value
+    /// assert!( ( 0.0..=1.0 ).contains( &perlin.noise3( 3e9, -3e9, 1e38 ) ) );
+    /// ```
+    ///
+    fn noise3(&self, x: f32, y: f32, z: f32) -> f32 {
+        let (xi, xf) = Perlin::split(x);
+        let (yi, yf) = Perlin::split(y);
+        let (zi, zf) = Perlin::split(z);
+        let (u, v, w) = (Perlin::fade(xf), Perlin::fade(yf), Perlin::fade(zf));
+
+        let corner = |dx: i32, dy: i32, dz: i32| {
+            let hash = self.hash(self.hash(self.hash(xi + dx) as i32 + yi + dy) as i32 + zi + dz);
+            let g = GRAD3[hash % 12];
+            g[0] * (xf - dx as f32) + g[1] * (yf - dy as f32) + g[2] * (zf - dz as f32)
+        };
+
+        let near = lerp(
+            lerp(corner(0, 0, 0), corner(1, 0, 0), u),
+            lerp(corner(0, 1, 0), corner(1, 1, 0), u),
+            v,
+        );
+        let far = lerp(
+            lerp(corner(0, 0, 1), corner(1, 0, 1), u),
+            lerp(corner(0, 1, 1), corner(1, 1, 1), u),
+            v,
+        );
+
+        normalize(lerp(near, far, w))
+    }
+}
+
+impl Perlin {
+    /* Looks up the permutation table, wrapping the index into 0 to 255 */
+    fn hash(&self, i: i32) -> usize {
+        self.permutation_table[(i & 255) as usize] as usize
+    }
+
+    /* The one dimensional gradient is either +1 or -1 */
+    fn grad1(&self, hash: usize, x: f32) -> f32 {
+        if hash & 1 == 0 {
+            x
+        } else {
+            -x
+        }
+    }
+
+    /* Splits a coordinate into its lattice cell, wrapped into 0 to 255 like every index into the
+    permutation table so the corner arithmetic can't overflow, and the offset within the cell */
+    fn split(x: f32) -> (i32, f32) {
+        let floor = x.floor();
+        (floor as i32 & 255, x - floor)
+    }
+
+    /* Perlin's quintic fade curve, 6t^5 - 15t^4 + 10t^3, which has zero first and second derivatives at 0 and 1 */
+    fn fade(t: f32) -> f32 {
+        t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+    }
+}
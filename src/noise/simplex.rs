@@ -0,0 +1,329 @@
+use super::{hash, normalize, permutation_table, NoiseFn, GRAD3};
+use crate::random;
+
+/// The 32 gradients of four dimensional simplex noise, the midpoints of the edges of a tesseract
+const GRAD4: [[f32; 4]; 32] = [
+    [0.0, 1.0, 1.0, 1.0],
+    [0.0, 1.0, 1.0, -1.0],
+    [0.0, 1.0, -1.0, 1.0],
+    [0.0, 1.0, -1.0, -1.0],
+    [0.0, -1.0, 1.0, 1.0],
+    [0.0, -1.0, 1.0, -1.0],
+    [0.0, -1.0, -1.0, 1.0],
+    [0.0, -1.0, -1.0, -1.0],
+    [1.0, 0.0, 1.0, 1.0],
+    [1.0, 0.0, 1.0, -1.0],
+    [1.0, 0.0, -1.0, 1.0],
+    [1.0, 0.0, -1.0, -1.0],
+    [-1.0, 0.0, 1.0, 1.0],
+    [-1.0, 0.0, 1.0, -1.0],
+    [-1.0, 0.0, -1.0, 1.0],
+    [-1.0, 0.0, -1.0, -1.0],
+    [1.0, 1.0, 0.0, 1.0],
+    [1.0, 1.0, 0.0, -1.0],
+    [1.0, -1.0, 0.0, 1.0],
+    [1.0, -1.0, 0.0, -1.0],
+    [-1.0, 1.0, 0.0, 1.0],
+    [-1.0, 1.0, 0.0, -1.0],
+    [-1.0, -1.0, 0.0, 1.0],
+    [-1.0, -1.0, 0.0, -1.0],
+    [1.0, 1.0, 1.0, 0.0],
+    [1.0, 1.0, -1.0, 0.0],
+    [1.0, -1.0, 1.0, 0.0],
+    [1.0, -1.0, -1.0, 0.0],
+    [-1.0, 1.0, 1.0, 0.0],
+    [-1.0, 1.0, -1.0, 0.0],
+    [-1.0, -1.0, 1.0, 0.0],
+    [-1.0, -1.0, -1.0, 0.0],
+];
+
+/// Simplex Noise struct
+///
+/// Generates Ken Perlin's simplex noise in one to four dimensions. Sampling a simplex instead of a
+/// hypercube gives fewer directional artifacts than Perlin, and costs far less in higher dimensions.
+/// Values are normalized to [0,1] and seeded the same way as Perlin
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let simplex = Simplex::new( DEFAULT_NOISE_SEED );
+///
+/// let a = simplex.noise2( 0.5, 1.25 );
+///
+/// assert!( ( 0.0..=1.0 ).contains( &a ) );
+/// assert!( ( a - simplex.noise2( 0.5001, 1.25 ) ).abs() < 0.001 );
+/// assert_eq!( a, Simplex::new( DEFAULT_NOISE_SEED ).get( Vec2::new( &0.5, &1.25 ) ) );
+///
+/// // Coordinates past the range of i32 still give a value
+/// assert!( ( 0.0..=1.0 ).contains( &simplex.noise3( 3e9, -3e9, 1e38 ) ) );
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Simplex {
+    /// The seed the noise was generated from
+    pub seed: usize,
+    /// Shuffled table of the values 0 to 255
+    permutation_table: [u8; 256],
+}
+
+impl Simplex {
+    /// Initializes and returns a new Simplex object
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Simplex::new( DEFAULT_NOISE_SEED );
+    /// let b = Simplex::new( 42 );
+    ///
+    /// assert_eq!( a.noise3( 0.3, 1.7, 2.9 ), Simplex::new( DEFAULT_NOISE_SEED ).noise3( 0.3, 1.7, 2.9 ) );
+    /// assert_ne!( a.noise3( 0.3, 1.7, 2.9 ), b.noise3( 0.3, 1.7, 2.9 ) );
+    /// ```
+    ///
+    pub fn new(seed: usize) -> Simplex {
+        Simplex {
+            seed,
+            permutation_table: permutation_table(seed),
+        }
+    }
+
+    /// Initializes and returns a new Simplex object with a seed picked at random
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Simplex::with_random_seed();
+    /// let b = Simplex::new( a.seed );
+    ///
+    /// assert_eq!( a.noise2( 4.2, 0.7 ), b.noise2( 4.2, 0.7 ) );
+    /// ```
+    ///
+    pub fn with_random_seed() -> Simplex {
+        Simplex::new(random(0..usize::MAX))
+    }
+
+    /// Rebuilds the permutation table from a new seed, as if self was created with Simplex::new( seed )
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let mut a = Simplex::new( 7 );
+    /// let before = a.noise3( 2.5, 0.3, 1.1 );
+    ///
+    /// a.reseed( 8 );
+    /// assert_eq!( a.seed, 8 );
+    /// assert_eq!( a.noise3( 2.5, 0.3, 1.1 ), Simplex::new( 8 ).noise3( 2.5, 0.3, 1.1 ) );
+    ///
+    /// a.reseed( 7 );
+    /// assert_eq!( a.noise3( 2.5, 0.3, 1.1 ), before );
+    /// ```
+    ///
+    pub fn reseed(&mut self, seed: usize) {
+        self.seed = seed;
+        self.permutation_table = permutation_table(seed);
+    }
+
+    /// Gets a value between 0 and 1 using four dimensional simplex noise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let simplex = Simplex::new( DEFAULT_NOISE_SEED );
+    ///
+    /// // Looping the fourth dimension animates three dimensional noise smoothly
+    /// for i in 0..20 {
+    ///     for j in 0..20 {
+    ///         for k in 0..20 {
+    ///             let ( x, y, z ) = ( i as f32 * 0.21, j as f32 * 0.23, k as f32 * 0.19 );
+    ///             let value = simplex.noise4( x, y, z, 1.5 );
+    ///
+    ///             assert!( ( 0.0..=1.0 ).contains( &value ) );
+    ///             assert!( ( value - simplex.noise4( x, y, z, 1.501 ) ).abs() < 0.02 );
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub fn noise4(&self, x: f32, y: f32, z: f32, w: f32) -> f32 {
+        normalize(SCALE4 * self.simplex([x, y, z, w], F4, G4, 0.5))
+    }
+
+    /* Sums the contributions of the corners of the simplex containing point, skew and unskew are
+    the factors mapping between the simplex grid and the hypercube grid. Each corner reaches a
+    squared distance of radius_sq, past 0.5 the corners overlap simplices they aren't part of and
+    the noise jumps at the simplex edges */
+    fn simplex<const N: usize>(
+        &self,
+        point: [f32; N],
+        skew: f32,
+        unskew: f32,
+        radius_sq: f32,
+    ) -> f32 {
+        /* Skew the point to find the hypercube cell it is in */
+        let skewed = point.iter().sum::<f32>() * skew;
+        let mut cell = [0; N];
+        for (c, p) in cell.iter_mut().zip(point.iter()) {
+            *c = (p + skewed).floor() as i32;
+        }
+
+        /* The offset of the point from the first corner, back in unskewed space */
+        let unskewed = cell.iter().map(|&c| c as i64).sum::<i64>() as f32 * unskew;
+        let mut offset = [0.0; N];
+        for i in 0..N {
+            offset[i] = point[i] - (cell[i] as f32 - unskewed);
+        }
+
+        /* The simplex is walked by stepping along the axes in order of largest offset first */
+        let mut order = [0; N];
+        for (i, o) in order.iter_mut().enumerate() {
+            *o = i;
+        }
+        order.sort_by(|&a, &b| offset[b].total_cmp(&offset[a]));
+
+        let mut total = 0.0;
+        let mut corner = cell;
+        for step in 0..=N {
+            if step > 0 {
+                corner[order[step - 1]] = corner[order[step - 1]].wrapping_add(1);
+            }
+
+            let mut d = [0.0; N];
+            for i in 0..N {
+                d[i] = offset[i] - corner[i].wrapping_sub(cell[i]) as f32 + step as f32 * unskew;
+            }
+
+            let falloff = radius_sq - d.iter().map(|v| v * v).sum::<f32>();
+            if falloff > 0.0 {
+                let falloff = falloff * falloff;
+                total += falloff * falloff * gradient(hash(&self.permutation_table, &corner), &d);
+            }
+        }
+
+        total
+    }
+}
+
+/// Implements NoiseFn for Simplex
+impl NoiseFn for Simplex {
+    /// Gets a value between 0 and 1 using one dimensional simplex noise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let simplex = Simplex::new( DEFAULT_NOISE_SEED );
+    ///
+    /// let mut last = simplex.noise1( 0.0 );
+    /// for i in 1..10000 {
+    ///     let value = simplex.noise1( i as f32 * 0.001 );
+    ///
+    ///     assert!( ( 0.0..=1.0 ).contains( &value ) );
+    ///     assert!( ( value - last ).abs() < 0.01 );
+    ///     last = value;
+    /// }
+    /// ```
+    ///
+    fn noise1(&self, x: f32) -> f32 {
+        normalize(SCALE1 * self.simplex([x], 0.0, 0.0, 1.0))
+    }
+
+    /// Gets a value between 0 and 1 using two dimensional simplex noise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let simplex = Simplex::new( DEFAULT_NOISE_SEED );
+    ///
+    /// for i in 0..200 {
+    ///     for j in 0..200 {
+    ///         let ( x, y ) = ( i as f32 * 0.037, j as f32 * 0.041 );
+    ///         let value = simplex.noise2( x, y );
+    ///
+    ///         assert!( ( 0.0..=1.0 ).contains( &value ) );
+    ///         assert!( ( value - simplex.noise2( x + 0.001, y ) ).abs() < 0.01 );
+    ///         assert!( ( value - simplex.noise2( x, y + 0.001 ) ).abs() < 0.01 );
+    ///     }
+    /// }
+    /// ```
+    ///
+    fn noise2(&self, x: f32, y: f32) -> f32 {
+        normalize(SCALE2 * self.simplex([x, y], F2, G2, 0.5))
+    }
+
+    /// Gets a value between 0 and 1 using three dimensional simplex noise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let simplex = Simplex::new( DEFAULT_NOISE_SEED );
+    ///
+    /// for i in 0..40 {
+    ///     for j in 0..40 {
+    ///         for k in 0..20 {
+    ///             let ( x, y, z ) = ( i as f32 * 0.13, j as f32 * 0.17, k as f32 * 0.11 );
+    ///             let value = simplex.noise3( x, y, z );
+    ///
+    ///             assert!( ( 0.0..=1.0 ).contains( &value ) );
+    ///             assert!( ( value - simplex.noise3( x, y, z + 0.001 ) ).abs() < 0.01 );
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    fn noise3(&self, x: f32, y: f32, z: f32) -> f32 {
+        normalize(SCALE3 * self.simplex([x, y, z], F3, G3, 0.5))
+    }
+}
+
+/* The skew and unskew factors, ( sqrt( n + 1 ) - 1 ) / n and ( 1 - 1 / sqrt( n + 1 ) ) / n */
+pub(super) const F2: f32 = 0.366_025_42;
+pub(super) const G2: f32 = 0.211_324_87;
+const F3: f32 = 1.0 / 3.0;
+const G3: f32 = 1.0 / 6.0;
+const F4: f32 = 0.309_017;
+const G4: f32 = 0.138_196_6;
+
+/* Scale the sum of the corners to about [-1,1] in each dimension, measured from dense sampling */
+const SCALE1: f32 = 0.395;
+const SCALE2: f32 = 70.0;
+const SCALE3: f32 = 74.0;
+const SCALE4: f32 = 60.0;
+
+/* The dot product of the gradient picked by hash with the offset d */
+fn gradient<const N: usize>(hash: usize, d: &[f32; N]) -> f32 {
+    match N {
+        /* Gradients from 1 to 8, either sign */
+        1 => {
+            let g = (1 + (hash & 7)) as f32;
+            if hash & 8 == 0 {
+                g * d[0]
+            } else {
+                -g * d[0]
+            }
+        }
+        2 | 3 => GRAD3[hash % 12]
+            .iter()
+            .zip(d.iter())
+            .map(|(g, v)| g * v)
+            .sum(),
+        _ => GRAD4[hash % 32]
+            .iter()
+            .zip(d.iter())
+            .map(|(g, v)| g * v)
+            .sum(),
+    }
+}
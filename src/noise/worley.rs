@@ -0,0 +1,284 @@
+use super::{NoiseFn, SplitMix64};
+use crate::random;
+
+/// The ways Worley measures the distance to a feature point
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DistanceMetric {
+    /// Straight line distance, giving round cells
+    Euclidean,
+    /// The sum of the distances along each axis, giving diamond shaped cells
+    Manhattan,
+    /// The largest distance along any axis, giving square cells
+    Chebyshev,
+}
+
+/// The values Worley can return for a point
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum WorleyReturn {
+    /// The distance to the nearest feature point
+    F1,
+    /// The distance to the second nearest feature point
+    F2,
+    /// The difference between F2 and F1, zero along the borders between cells
+    F2MinusF1,
+    /// A random value shared by every point in the cell of the nearest feature point
+    CellId,
+}
+
+/// Worley Noise struct
+///
+/// Generates Worley ( cellular ) noise, scattering one feature point in each cell of the integer
+/// grid and measuring the distance from the input to the nearest of them. Distances are divided by
+/// the largest distance within a cell under the metric in use, then clamped to [0,1]
+///
+/// # Examples
+///
+/// ```
+/// use miscmath::prelude::*;
+///
+/// let mut worley = Worley::new( DEFAULT_NOISE_SEED );
+///
+/// // Without jitter the feature points sit at the centre of each cell
+/// worley.set_jitter( &0.0 );
+///
+/// assert_eq!( worley.noise2( 0.5, 0.5 ), 0.0 );
+/// assert!( ( worley.noise2( 0.0, 0.5 ) - 0.5 / 2.0_f32.sqrt() ).abs() < 0.00001 );
+///
+/// worley.set_return_type( &WorleyReturn::F2MinusF1 );
+/// assert_eq!( worley.get( Vec2::new( &0.0, &0.5 ) ), 0.0 );
+///
+/// worley.set_metric( &DistanceMetric::Chebyshev );
+/// worley.set_return_type( &WorleyReturn::F1 );
+/// assert!( ( worley.get( Vec3::new( &0.1, &0.3, &0.5 ) ) - 0.4 ).abs() < 0.00001 );
+///
+/// // Infinite and NaN points give 0, and coordinates past the range of i32 still give a value
+/// assert_eq!( worley.noise2( f32::NAN, 0.5 ), 0.0 );
+/// assert_eq!( worley.noise3( 0.5, f32::INFINITY, 0.5 ), 0.0 );
+/// assert!( ( 0.0..=1.0 ).contains( &worley.noise3( 3e9, -3e9, 1e38 ) ) );
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Worley {
+    /// The seed the noise was generated from
+    pub seed: usize,
+    /// How distances to feature points are measured
+    metric: DistanceMetric,
+    /// What is returned for each point
+    return_type: WorleyReturn,
+    /// How far feature points stray from the centre of their cell, from 0 to 1
+    jitter: f32,
+}
+
+impl Worley {
+    /// Initializes and returns a new Worley object, returning F1 with the euclidean metric and full jitter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let a = Worley::new( DEFAULT_NOISE_SEED );
+    /// let b = Worley::new( 42 );
+    ///
+    /// assert_eq!( a.noise2( 0.3, 1.7 ), Worley::new( DEFAULT_NOISE_SEED ).noise2( 0.3, 1.7 ) );
+    /// assert_ne!( a.noise2( 0.3, 1.7 ), b.noise2( 0.3, 1.7 ) );
+    /// ```
+    ///
+    pub fn new(seed: usize) -> Worley {
+        Worley {
+            seed,
+            metric: DistanceMetric::Euclidean,
+            return_type: WorleyReturn::F1,
+            jitter: 1.0,
+        }
+    }
+
+    /// Initializes and returns a new Worley object with a seed picked at random
+    pub fn with_random_seed() -> Worley {
+        Worley::new(random(0..usize::MAX))
+    }
+
+    /// Moves the feature points to those of a new seed, as if self was created with Worley::new( seed )
+    pub fn reseed(&mut self, seed: usize) {
+        self.seed = seed;
+    }
+
+    /// Returns how distances to feature points are measured
+    pub fn metric(&self) -> DistanceMetric {
+        self.metric
+    }
+
+    /// Sets how distances to feature points are measured
+    pub fn set_metric(&mut self, metric: &DistanceMetric) {
+        self.metric = *metric;
+    }
+
+    /// Returns what is returned for each point
+    pub fn return_type(&self) -> WorleyReturn {
+        self.return_type
+    }
+
+    /// Sets what is returned for each point
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miscmath::prelude::*;
+    ///
+    /// let mut worley = Worley::new( DEFAULT_NOISE_SEED );
+    ///
+    /// for i in 0..100 {
+    ///     for j in 0..100 {
+    ///         let ( x, y ) = ( i as f32 * 0.047, j as f32 * 0.053 );
+    ///
+    ///         worley.set_return_type( &WorleyReturn::F1 );
+    ///         let f1 = worley.noise2( x, y );
+    ///         worley.set_return_type( &WorleyReturn::F2 );
+    ///         let f2 = worley.noise2( x, y );
+    ///         worley.set_return_type( &WorleyReturn::F2MinusF1 );
+    ///         let edge = worley.noise2( x, y );
+    ///
+    ///         assert!( f1 <= f2 );
+    ///         assert!( ( edge - ( f2 - f1 ) ).abs() < 0.00001 || f2 == 1.0 );
+    ///     }
+    /// }
+    ///
+    /// // Every point in a cell shares its id
+    /// worley.set_jitter( &0.0 );
+    /// worley.set_return_type( &WorleyReturn::CellId );
+    ///
+    /// assert_eq!( worley.noise2( 3.1, 4.2 ), worley.noise2( 3.9, 4.8 ) );
+    /// assert_ne!( worley.noise2( 3.1, 4.2 ), worley.noise2( 4.1, 4.2 ) );
+    /// ```
+    ///
+    pub fn set_return_type(&mut self, return_type: &WorleyReturn) {
+        self.return_type = *return_type;
+    }
+
+    /// Returns how far feature points stray from the centre of their cell
+    pub fn jitter(&self) -> f32 {
+        self.jitter
+    }
+
+    /// Sets how far feature points stray from the centre of their cell, 0 gives a regular grid and
+    /// 1 lets them fall anywhere in the cell. Values are clamped to [0,1]
+    pub fn set_jitter(&mut self, jitter: &f32) {
+        self.jitter = jitter.clamp(0.0, 1.0);
+    }
+
+    /* Finds the distances to the nearest and second nearest feature points, and the cell of the nearest */
+    fn search<const N: usize>(&self, point: [f32; N]) -> (f32, f32, [i32; N]) {
+        let base = point.map(|p| p.floor());
+        let cell = base.map(|b| b as i32);
+        let (mut f1, mut f2, mut nearest) = (f32::INFINITY, f32::INFINITY, cell);
+
+        /* Search outwards a ring of cells at a time, the cells of a ring are at least ring - 1 away
+        along one axis, so once that passes F2 no further cell can hold a nearer point. Every cell
+        holds a feature point, so F2 is always found by ring 2 and the search stops there */
+        let mut ring: i32 = 0;
+        while ring <= MAX_RING && ((ring - 1) as f32) < f2 {
+            let side = 2 * ring + 1;
+            for index in 0..side.pow(N as u32) {
+                let mut step = [0; N];
+                let mut rest = index;
+                for s in step.iter_mut() {
+                    *s = rest % side - ring;
+                    rest /= side;
+                }
+                if !step.iter().any(|s| s.abs() == ring) {
+                    continue;
+                }
+
+                let low: [f32; N] = std::array::from_fn(|i| base[i] + step[i] as f32);
+                let neighbour: [i32; N] = std::array::from_fn(|i| cell[i].wrapping_add(step[i]));
+
+                /* Skip cells too far away to hold a point nearer than F2 */
+                let mut gap = [0.0; N];
+                for i in 0..N {
+                    gap[i] = (low[i] - point[i]).max(point[i] - (low[i] + 1.0)).max(0.0);
+                }
+                if self.distance(&gap) >= f2 {
+                    continue;
+                }
+
+                let mut offset = [0.0; N];
+                for (i, o) in offset.iter_mut().enumerate() {
+                    let stray = self.jitter * (cell_random(self.seed, &neighbour, i as u64) - 0.5);
+                    *o = low[i] + 0.5 + stray - point[i];
+                }
+
+                let dist = self.distance(&offset);
+                if dist < f1 {
+                    f2 = f1;
+                    f1 = dist;
+                    nearest = neighbour;
+                } else if dist < f2 {
+                    f2 = dist;
+                }
+            }
+
+            ring += 1;
+        }
+
+        (f1, f2, nearest)
+    }
+
+    /* The distance covered by offset under the metric in use */
+    fn distance<const N: usize>(&self, offset: &[f32; N]) -> f32 {
+        match self.metric {
+            DistanceMetric::Euclidean => offset.iter().map(|o| o * o).sum::<f32>().sqrt(),
+            DistanceMetric::Manhattan => offset.iter().map(|o| o.abs()).sum(),
+            DistanceMetric::Chebyshev => offset.iter().fold(0.0, |max, o| o.abs().max(max)),
+        }
+    }
+
+    /* Samples the noise in N dimensions, mapping the search to the return type. Infinite and NaN
+    points have no cell, so they give 0 */
+    fn sample<const N: usize>(&self, point: [f32; N]) -> f32 {
+        if point.iter().any(|p| !p.is_finite()) {
+            return 0.0;
+        }
+
+        let (f1, f2, nearest) = self.search(point);
+
+        /* The largest distance within one cell, the diagonal under the metric */
+        let diagonal = self.distance(&[1.0; N]);
+
+        match self.return_type {
+            WorleyReturn::F1 => (f1 / diagonal).clamp(0.0, 1.0),
+            WorleyReturn::F2 => (f2 / diagonal).clamp(0.0, 1.0),
+            WorleyReturn::F2MinusF1 => ((f2 - f1) / diagonal).clamp(0.0, 1.0),
+            WorleyReturn::CellId => cell_random(self.seed, &nearest, N as u64),
+        }
+    }
+}
+
+/// Implements NoiseFn for Worley
+impl NoiseFn for Worley {
+    fn noise1(&self, x: f32) -> f32 {
+        self.sample([x])
+    }
+
+    fn noise2(&self, x: f32, y: f32) -> f32 {
+        self.sample([x, y])
+    }
+
+    fn noise3(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.sample([x, y, z])
+    }
+}
+
+/* The furthest ring of cells searched around the cell of a point */
+const MAX_RING: i32 = 2;
+
+/* A random value in [0,1) fixed by the seed, the cell and a salt, used for the position of the
+feature point along each axis and for the id of the cell */
+fn cell_random<const N: usize>(seed: usize, cell: &[i32; N], salt: u64) -> f32 {
+    let mut key = SplitMix64::new(SplitMix64::new(seed as u64).next() ^ salt).next();
+    for c in cell {
+        key = SplitMix64::new(key ^ *c as u32 as u64).next();
+    }
+
+    /* The top 24 bits fill the mantissa of a f32 exactly */
+    (key >> 40) as f32 / (1u64 << 24) as f32
+}
@@ -1,9 +1,29 @@
 pub use super::constrain;
 pub use super::factorial;
-pub use super::linear::vector::{Vec2, Vec3};
+pub use super::linear::affine::{Affine2, TransformStack};
+pub use super::linear::complex::Complex;
+pub use super::linear::coords::{Cylindrical, Polar, Spherical};
+pub use super::linear::iterative::{
+    bicgstab, conjugate_gradient, gmres, ConvergenceReport, LinearOperator, Preconditioner,
+    SolverConfig,
+};
+pub use super::linear::matrix::{
+    least_squares, Cholesky, DMatrix, DMatrixView, Ldlt, Lu, Mat2, Mat3, Mat4, Matrix, MatrixError,
+    Qr, Svd, SymmetricEigen,
+};
+pub use super::linear::quat::Quat;
+pub use super::linear::scalar::{Float, Scalar};
+pub use super::linear::sparse::{CooMatrix, CscMatrix, CsrMatrix};
+pub use super::linear::vector::{
+    Vec2, Vec2d, Vec2f, Vec2i, Vec3, Vec3d, Vec3f, Vec3i, Vec4, Vec4d, Vec4f, Vec4i, VecN,
+};
 pub use super::linear::CoordSystem;
 pub use super::linear::UnitF;
 pub use super::map;
+pub use super::noise::{
+    DistanceMetric, Fractal, FractalMode, NoiseFn, NoiseInput, OpenSimplex2, Simplex, Worley,
+    WorleyReturn,
+};
 pub use super::random;
 pub use super::Perlin;
 pub use super::DEFAULT_NOISE_SEED;